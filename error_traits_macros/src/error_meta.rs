@@ -0,0 +1,159 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Attribute, Data, DeriveInput, Error, LitInt, LitStr, Result};
+
+/// Values of a single `#[error_meta(...)]` attribute.
+#[derive(Default, Clone)]
+struct Meta {
+    code: Option<LitStr>,
+    severity: Option<TokenStream>,
+    status: Option<LitInt>,
+}
+
+impl Meta {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut meta = Meta::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("error_meta")) {
+            attr.parse_nested_meta(|nested| {
+                if nested.path.is_ident("code") {
+                    meta.code = Some(nested.value()?.parse()?);
+                } else if nested.path.is_ident("severity") {
+                    let lit: LitStr = nested.value()?.parse()?;
+                    meta.severity = Some(severity(&lit)?);
+                } else if nested.path.is_ident("status") {
+                    let lit: LitInt = nested.value()?.parse()?;
+                    match lit.base10_parse::<u16>()? {
+                        100..=999 => meta.status = Some(lit),
+                        _ => return Err(Error::new_spanned(lit, "status must be in 100..=999")),
+                    }
+                } else {
+                    return Err(nested.error("expected `code`, `severity` or `status`"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(meta)
+    }
+
+    /// Values of `self`, falling back to `default` for missing ones.
+    fn or(self, default: &Meta) -> Meta {
+        Meta {
+            code: self.code.or_else(|| default.code.clone()),
+            severity: self.severity.or_else(|| default.severity.clone()),
+            status: self.status.or_else(|| default.status.clone()),
+        }
+    }
+}
+
+fn severity(lit: &LitStr) -> Result<TokenStream> {
+    let level = match lit.value().as_str() {
+        "info" => quote!(Info),
+        "warn" | "warning" => quote!(Warning),
+        "error" => quote!(Error),
+        "fatal" => quote!(Fatal),
+        _ => {
+            return Err(Error::new_spanned(
+                lit,
+                "expected \"info\", \"warn\", \"error\" or \"fatal\"",
+            ))
+        }
+    };
+    Ok(quote!(::error_traits::SeverityLevel::#level))
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let default = Meta::parse(&input.attrs)?;
+    // Pattern and attributes of every value of the type.
+    let arms: Vec<(TokenStream, Meta)> = match &input.data {
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|v| {
+                let ident = &v.ident;
+                Ok((
+                    quote!(Self::#ident { .. }),
+                    Meta::parse(&v.attrs)?.or(&default),
+                ))
+            })
+            .collect::<Result<_>>()?,
+        Data::Struct(_) => vec![(quote!(Self { .. }), default.clone())],
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input.ident,
+                "`ErrorMeta` can't be derived for unions",
+            ))
+        }
+    };
+
+    let code = trait_impl(&input, &arms, "code", |m| {
+        m.code.as_ref().map(|c| c.to_token_stream())
+    })?
+    .map(|arms| {
+        quote! {
+            fn code(&self) -> &'static str {
+                match self { #arms }
+            }
+        }
+    });
+    let severity = trait_impl(&input, &arms, "severity", |m| m.severity.clone())?.map(|arms| {
+        quote! {
+            fn severity(&self) -> ::error_traits::SeverityLevel {
+                match self { #arms }
+            }
+        }
+    });
+    let status = trait_impl(&input, &arms, "status", |m| {
+        m.status.as_ref().map(|s| {
+            quote!(::error_traits::StatusCode::from_u16(#s).expect("status is checked by `ErrorMeta`"))
+        })
+    })?
+    .map(|arms| {
+        quote! {
+            fn status(&self) -> ::error_traits::StatusCode {
+                match self { #arms }
+            }
+        }
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let impls = [
+        (code, quote!(::error_traits::ErrorCode)),
+        (severity, quote!(::error_traits::Severity)),
+        (status, quote!(::error_traits::HttpStatus)),
+    ]
+    .into_iter()
+    .filter_map(|(body, tr)| {
+        body.map(|body| {
+            quote! {
+                impl #impl_generics #tr for #ident #ty_generics #where_clause {
+                    #body
+                }
+            }
+        })
+    });
+    Ok(quote!(#(#impls)*))
+}
+
+/// Match arms for one trait, or `None` if no value of the type specifies `name`.
+/// Either all of them, or none must specify it.
+fn trait_impl(
+    input: &DeriveInput,
+    arms: &[(TokenStream, Meta)],
+    name: &str,
+    value: impl Fn(&Meta) -> Option<TokenStream>,
+) -> Result<Option<TokenStream>> {
+    let values: Vec<_> = arms.iter().map(|(pat, meta)| (pat, value(meta))).collect();
+    if values.iter().all(|(_, v)| v.is_none()) {
+        return Ok(None);
+    }
+    if let Some((pat, _)) = values.iter().find(|(_, v)| v.is_none()) {
+        let msg = format!(
+            "`{name}` is missing for `{pat}`, set it here or on `{}`",
+            input.ident
+        );
+        return Err(Error::new_spanned(&input.ident, msg));
+    }
+    let arms = values.into_iter().map(|(pat, v)| quote!(#pat => #v,));
+    Ok(Some(quote!(#(#arms)*)))
+}
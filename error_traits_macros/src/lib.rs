@@ -0,0 +1,110 @@
+//! Procedural macros of `error_traits`, re-exported by it behind the `macros` feature.
+
+mod error_meta;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::visit_mut::{self, VisitMut};
+use syn::{parse_macro_input, FnArg, ItemFn, Pat, Type};
+
+/// Logs an error returned from the function once, through `error_traits::LogErr`.
+/// See `error_traits::log_errors` for details and examples.
+#[proc_macro_attribute]
+pub fn log_errors(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return syn::Error::new_spanned(attr, "`log_errors` takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = parse_macro_input!(item as ItemFn);
+
+    let name = sig.ident.to_string();
+    let args = sig
+        .inputs
+        .iter()
+        .map(|arg| match arg {
+            FnArg::Receiver(receiver) => tidy(quote!(#receiver)),
+            FnArg::Typed(typed) => {
+                let ty = &typed.ty;
+                match &*typed.pat {
+                    Pat::Ident(ident) => format!("{}: {}", ident.ident, tidy(quote!(#ty))),
+                    pat => format!("{}: {}", tidy(quote!(#pat)), tidy(quote!(#ty))),
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let prefix = format!("{name}({args}): ");
+    // `impl Trait` can't be named inside the body, but the rest of the type
+    // (like the error type `?` converts into) is still needed for inference.
+    let output = match &sig.output {
+        syn::ReturnType::Default => quote!(()),
+        syn::ReturnType::Type(_, ty) => {
+            let mut ty = (**ty).clone();
+            InferImplTrait.visit_type_mut(&mut ty);
+            quote!(#ty)
+        }
+    };
+
+    // A closure (or an `async` block), so that `?` and `return` in the body
+    // produce the result to log, instead of leaving the function.
+    // Closure is called through `FnOnce` bound, so it may return borrows of captured arguments.
+    let body = match sig.asyncness {
+        Some(_) => quote!(async move #block.await),
+        None => quote!(::error_traits::__call_once(|| -> #output #block)),
+    };
+    quote! {
+        #(#attrs)*
+        #vis #sig {
+            let result: #output = #body;
+            ::error_traits::LogErr::log_err_with(result, || {
+                ::std::format!("{}::{}", ::std::module_path!(), #prefix)
+            })
+        }
+    }
+    .into()
+}
+
+/// Replaces every `impl Trait` in a type with `_`.
+struct InferImplTrait;
+
+impl VisitMut for InferImplTrait {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        match ty {
+            Type::ImplTrait(_) => *ty = Type::Infer(syn::parse_quote!(_)),
+            ty => visit_mut::visit_type_mut(self, ty),
+        }
+    }
+}
+
+/// Removes spaces `quote` puts between every two tokens, where code usually has none,
+/// so `& 'a mut Vec < u8 >` becomes `&'a mut Vec<u8>`.
+fn tidy(tokens: proc_macro2::TokenStream) -> String {
+    [
+        ("& ", "&"),
+        (" :: ", "::"),
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+    ]
+    .iter()
+    .fold(tokens.to_string(), |s, (from, to)| s.replace(from, to))
+}
+
+/// Implements `ErrorCode`, `Severity` and `HttpStatus` of `error_traits`
+/// from `#[error_meta(...)]` attributes.
+/// See `error_traits::ErrorMeta` for details and examples.
+#[proc_macro_derive(ErrorMeta, attributes(error_meta))]
+pub fn derive_error_meta(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as syn::DeriveInput);
+    error_meta::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
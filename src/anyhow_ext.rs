@@ -0,0 +1,40 @@
+use crate::{IntoExitCode, StdResult};
+use std::error::Error;
+use std::fmt::Display;
+
+/// Conversions for codebases mixing this crate and `anyhow`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::IntoAnyhow;
+///
+/// let res: anyhow::Result<u16> = "foo".parse::<u16>().into_anyhow();
+/// let res: anyhow::Result<u16> = "foo".parse::<u16>().map_err_anyhow_ctx("parsing port");
+/// assert_eq!(format!("{:#}", res.unwrap_err()), "parsing port: invalid digit found in string");
+/// ```
+pub trait IntoAnyhow<T> {
+    fn into_anyhow(self) -> anyhow::Result<T>;
+
+    fn map_err_anyhow_ctx<C>(self, context: C) -> anyhow::Result<T>
+    where
+        C: Display + Send + Sync + 'static;
+}
+
+impl<T, E> IntoAnyhow<T> for StdResult<T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn into_anyhow(self) -> anyhow::Result<T> {
+        self.map_err(anyhow::Error::new)
+    }
+
+    fn map_err_anyhow_ctx<C>(self, context: C) -> anyhow::Result<T>
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.map_err(|e| anyhow::Error::new(e).context(context))
+    }
+}
+
+impl IntoExitCode for anyhow::Error {}
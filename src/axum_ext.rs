@@ -0,0 +1,154 @@
+use crate::{ErrorCode, HttpStatus, StdResult, ToSerializableError};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Error returned from `axum` handlers. Responds with error's `HttpStatus`
+/// and a `SerializableError` JSON body, with `code` filled from
+/// `HttpStatus::error_code` (or from `ErrorCode` if built with `ApiError::coded`).
+/// Messages go through the global redactor (see `set_redactor`).
+/// Bodies of 5xx responses only have the message and the code,
+/// unless `ApiError::with_sources` is used.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::IntoResponse;
+/// use http::StatusCode;
+/// use error_traits::{ApiError, WithCode, WithContext};
+///
+/// let error = Err::<(), _>(std::io::Error::other("disk failure"))
+///     .context("loading config")
+///     .map_err_with_code("E_CONFIG")
+///     .unwrap_err();
+/// let response = ApiError::from(error).into_response();
+/// assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+///
+/// let body = axum::body::to_bytes(response.into_body(), usize::MAX);
+/// let body = futures::executor::block_on(body).unwrap();
+/// let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+/// assert_eq!(body, serde_json::json!({ "message": "loading config", "chain": [], "code": "E_CONFIG" }));
+/// ```
+#[derive(Debug)]
+pub struct ApiError<E> {
+    error: E,
+    code: Option<&'static str>,
+    with_sources: bool,
+}
+
+impl<E> ApiError<E> {
+    pub fn new(error: E) -> Self {
+        Self {
+            error,
+            code: None,
+            with_sources: false,
+        }
+    }
+
+    /// Same as `new`, but puts error's `ErrorCode` into the body.
+    pub fn coded(error: E) -> Self
+    where
+        E: ErrorCode,
+    {
+        let code = Some(error.code());
+        Self {
+            code,
+            ..Self::new(error)
+        }
+    }
+
+    /// Includes type name and messages of error's sources into bodies of 5xx responses too.
+    pub fn with_sources(self) -> Self {
+        Self {
+            with_sources: true,
+            ..self
+        }
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> From<E> for ApiError<E> {
+    fn from(error: E) -> Self {
+        Self::new(error)
+    }
+}
+
+impl<E> Display for ApiError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E> IntoResponse for ApiError<E>
+where
+    E: Error + HttpStatus + 'static,
+{
+    fn into_response(self) -> Response {
+        let status = self.error.status();
+        let mut body = self.error.to_serializable();
+        if status.is_server_error() && !self.with_sources {
+            body.type_name.clear();
+            body.chain.clear();
+        }
+        if let Some(redactor) = crate::redactor() {
+            body.message = redactor.redact(&body.message);
+            for msg in &mut body.chain {
+                *msg = redactor.redact(msg);
+            }
+        }
+        if let Some(code) = self.code.or_else(|| self.error.error_code()) {
+            body = body.with_code(code)
+        }
+        (status, Json(body)).into_response()
+    }
+}
+
+/// Wraps error into `ApiError`, so handlers can just write `fetch().into_api_err()?`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::response::IntoResponse;
+/// use http::StatusCode;
+/// use error_traits::{ApiError, IntoApiErr};
+///
+/// async fn config() -> Result<String, ApiError<std::io::Error>> {
+///     let config = std::fs::read_to_string("missing/config.toml").into_api_err()?;
+///     Ok(config)
+/// }
+///
+/// let response = futures::executor::block_on(config()).into_response();
+/// assert_eq!(response.status(), StatusCode::NOT_FOUND);
+/// ```
+pub trait IntoApiErr<T, E> {
+    fn into_api_err(self) -> StdResult<T, ApiError<E>>;
+
+    /// Same as `into_api_err`, but includes error's `ErrorCode` in the body.
+    fn into_api_err_coded(self) -> StdResult<T, ApiError<E>>
+    where
+        E: ErrorCode;
+}
+
+impl<T, E> IntoApiErr<T, E> for StdResult<T, E> {
+    fn into_api_err(self) -> StdResult<T, ApiError<E>> {
+        self.map_err(ApiError::new)
+    }
+
+    fn into_api_err_coded(self) -> StdResult<T, ApiError<E>>
+    where
+        E: ErrorCode,
+    {
+        self.map_err(ApiError::coded)
+    }
+}
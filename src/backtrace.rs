@@ -0,0 +1,78 @@
+use crate::StdResult;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Error together with the backtrace captured at the moment it was wrapped.
+/// Backtrace is only captured (and displayed) if `RUST_BACKTRACE`
+/// or `RUST_LIB_BACKTRACE` is set, see `Backtrace::capture`.
+#[derive(Debug)]
+pub struct WithBacktrace<E> {
+    error: E,
+    backtrace: Backtrace,
+}
+
+impl<E> WithBacktrace<E> {
+    pub fn new(error: E) -> Self {
+        Self {
+            error,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for WithBacktrace<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.backtrace.status() {
+            BacktraceStatus::Captured => {
+                write!(f, "{}\n\nstack backtrace:\n{}", self.error, self.backtrace)
+            }
+            _ => Display::fmt(&self.error, f),
+        }
+    }
+}
+
+impl<E> Error for WithBacktrace<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Wraps error (if any) into `WithBacktrace`.
+/// Nothing is captured on the `Ok` path.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::CaptureBacktrace;
+///
+/// let error = "foo".parse::<u16>().capture_backtrace().unwrap_err();
+/// println!("{error}");
+/// ```
+pub trait CaptureBacktrace<T, E> {
+    fn capture_backtrace(self) -> StdResult<T, WithBacktrace<E>>;
+}
+
+impl<T, E> CaptureBacktrace<T, E> for StdResult<T, E> {
+    fn capture_backtrace(self) -> StdResult<T, WithBacktrace<E>> {
+        self.map_err(WithBacktrace::new)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::StdResult;
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::panic::{catch_unwind, UnwindSafe};
+
+/// Panic caught by `catch_panic`, with payload turned into a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicError {
+    message: String,
+}
+
+impl PanicError {
+    /// Builds an error out of a payload returned by `std::panic::catch_unwind`.
+    pub fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(message) => (*message).to_owned(),
+                Err(_) => "Box<dyn Any>".to_owned(),
+            },
+        };
+        Self { message }
+    }
+
+    /// Message the code panicked with.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for PanicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "panicked: {}", self.message)
+    }
+}
+
+impl Error for PanicError {}
+
+/// Runs `f`, turning a panic into `PanicError`.
+/// Useful at FFI and thread boundaries, where panics must not unwind any further.
+/// Wrap `f` in `std::panic::AssertUnwindSafe` if it captures references to mutable state.
+/// The panic hook still runs, so the panic is reported as usual.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{catch_panic, MapErrToString};
+///
+/// let result = catch_panic(|| -> u16 { panic!("boom") });
+/// assert_eq!(result.map_err_to_str(), Err("panicked: boom".to_owned()));
+///
+/// let number = catch_panic(|| 42);
+/// assert_eq!(number, Ok(42));
+/// ```
+pub fn catch_panic<F, T>(f: F) -> StdResult<T, PanicError>
+where
+    F: FnOnce() -> T + UnwindSafe,
+{
+    catch_unwind(f).map_err(PanicError::from_payload)
+}
+
+/// Same as `catch_panic`, but for a future: a panic while polling it
+/// completes the returned future with `PanicError`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::catch_panic_async;
+///
+/// let fut = catch_panic_async(async { "foo".parse::<u16>().expect("boom") });
+/// let result = futures::executor::block_on(fut);
+/// assert!(result.unwrap_err().message().starts_with("boom"));
+/// ```
+#[cfg(feature = "future_ext")]
+pub fn catch_panic_async<Fut>(fut: Fut) -> CatchPanicFuture<Fut>
+where
+    Fut: std::future::Future + UnwindSafe,
+{
+    CatchPanicFuture { fut }
+}
+
+#[cfg(feature = "future_ext")]
+pin_project_lite::pin_project! {
+    /// Future for the `catch_panic_async` function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CatchPanicFuture<Fut> {
+        #[pin]
+        fut: Fut,
+    }
+}
+
+#[cfg(feature = "future_ext")]
+impl<Fut> std::future::Future for CatchPanicFuture<Fut>
+where
+    Fut: std::future::Future + UnwindSafe,
+{
+    type Output = StdResult<Fut::Output, PanicError>;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let fut = self.project().fut;
+        // `Fut: UnwindSafe` is required, so asserting it for the pinned reference is sound.
+        match catch_unwind(std::panic::AssertUnwindSafe(|| fut.poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => std::task::Poll::Ready(Err(PanicError::from_payload(payload))),
+        }
+    }
+}
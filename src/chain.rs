@@ -0,0 +1,289 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::iter::FusedIterator;
+
+/// Iterator over an error and its `source()` chain, see `ChainExt::chain`.
+#[derive(Debug, Clone)]
+pub struct Chain<'a> {
+    next: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> Chain<'a> {
+    pub fn new(error: &'a (dyn Error + 'static)) -> Self {
+        Self { next: Some(error) }
+    }
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = current.source();
+        Some(current)
+    }
+}
+
+impl FusedIterator for Chain<'_> {}
+
+/// Displays an error with all of its causes, see `ChainExt::display_chain`.
+#[derive(Debug, Clone)]
+pub struct DisplayChain<'a> {
+    chain: Chain<'a>,
+}
+
+impl Display for DisplayChain<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, e) in self.chain.clone().enumerate() {
+            match i {
+                0 => write!(f, "error: {e}")?,
+                _ => write!(f, "\ncaused by: {e}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Displays an error with its causes as an indented tree, see `ChainExt::pretty_chain`.
+#[derive(Debug, Clone)]
+pub struct PrettyChain<'a> {
+    chain: Chain<'a>,
+}
+
+impl Display for PrettyChain<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, e) in self.chain.clone().enumerate() {
+            let (branch, indent) = match i {
+                0 => (String::new(), String::new()),
+                _ => (
+                    format!("\n{:1$}└─ caused by: ", "", (i - 1) * 3),
+                    " ".repeat(i * 3),
+                ),
+            };
+            f.write_str(&branch)?;
+            // Continuation lines of multi-line messages stay under their branch.
+            for (n, line) in e.to_string().lines().enumerate() {
+                match n {
+                    0 => f.write_str(line)?,
+                    _ => write!(f, "\n{indent}{line}")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Walks causes of an error without depending on `anyhow`.
+/// Chain starts with the error itself, followed by its sources.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{ChainExt, WithContext};
+///
+/// let error = "foo".parse::<u16>().context("parsing port").unwrap_err();
+/// let messages: Vec<String> = error.chain().map(|e| e.to_string()).collect();
+/// assert_eq!(messages, ["parsing port", "invalid digit found in string"]);
+/// assert_eq!(error.root_cause().to_string(), "invalid digit found in string");
+///
+/// let cause = error.find_source::<std::num::ParseIntError>();
+/// assert!(cause.is_some());
+///
+/// assert_eq!(
+///     error.display_chain().to_string(),
+///     "error: parsing port\ncaused by: invalid digit found in string"
+/// );
+///
+/// let error = Err::<(), _>(error).context("loading config").unwrap_err();
+/// assert_eq!(
+///     error.pretty_chain().to_string(),
+///     "loading config\n└─ caused by: parsing port\n   └─ caused by: invalid digit found in string"
+/// );
+/// ```
+pub trait ChainExt {
+    fn chain(&self) -> Chain<'_>;
+
+    /// The deepest source in the chain, or the error itself if it has no source.
+    fn root_cause(&self) -> &(dyn Error + 'static) {
+        self.chain()
+            .last()
+            .expect("chain always contains the error itself")
+    }
+
+    /// Displays the whole chain as `error: X`, followed by a `caused by: Y`
+    /// line for every source.
+    fn display_chain(&self) -> DisplayChain<'_> {
+        DisplayChain {
+            chain: self.chain(),
+        }
+    }
+
+    /// Displays the whole chain as a tree, every source indented
+    /// under its parent. Stays readable for deeply nested errors.
+    fn pretty_chain(&self) -> PrettyChain<'_> {
+        PrettyChain {
+            chain: self.chain(),
+        }
+    }
+
+    /// The first error in the chain (including the error itself)
+    /// that can be downcast to `T`.
+    fn find_source<T>(&self) -> Option<&T>
+    where
+        T: Error + 'static,
+    {
+        self.chain().find_map(|e| e.downcast_ref::<T>())
+    }
+}
+
+impl<E> ChainExt for E
+where
+    E: Error + 'static,
+{
+    fn chain(&self) -> Chain<'_> {
+        Chain::new(self)
+    }
+}
+
+impl ChainExt for dyn Error {
+    fn chain(&self) -> Chain<'_> {
+        Chain::new(self)
+    }
+}
+
+impl ChainExt for dyn Error + Send + Sync {
+    fn chain(&self) -> Chain<'_> {
+        Chain::new(self)
+    }
+}
+
+/// Same as `MapErrToString`, but stringifies the root cause of the error
+/// instead of the outermost wrapper.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{MapErrToRootString, WithContext};
+///
+/// let res = "foo".parse::<u16>().context("parsing port").map_err_to_root_str();
+/// assert_eq!(res, Err("invalid digit found in string".to_owned()));
+/// ```
+pub trait MapErrToRootString<T> {
+    fn map_err_to_root_str(self) -> StdResult<T, String>;
+}
+
+impl<T, E> MapErrToRootString<T> for StdResult<T, E>
+where
+    E: Error + 'static,
+{
+    fn map_err_to_root_str(self) -> StdResult<T, String> {
+        self.map_err(|e| e.root_cause().to_string())
+    }
+}
+
+/// Same as `MapErrToString`, but keeps causes of the error, formatting it
+/// with `ChainExt::display_chain`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{MapErrChainToString, WithContext};
+///
+/// let res = "foo".parse::<u16>().context("parsing port").map_err_chain_to_str();
+/// assert_eq!(
+///     res,
+///     Err("error: parsing port\ncaused by: invalid digit found in string".to_owned())
+/// );
+/// ```
+pub trait MapErrChainToString<T> {
+    fn map_err_chain_to_str(self) -> StdResult<T, String>;
+}
+
+impl<T, E> MapErrChainToString<T> for StdResult<T, E>
+where
+    E: Error + 'static,
+{
+    fn map_err_chain_to_str(self) -> StdResult<T, String> {
+        self.map_err(|e| e.display_chain().to_string())
+    }
+}
+
+/// If any error in the chain can be downcast to `C`, turns the whole
+/// `Result` into `Ok` with a value built by `f`. Other errors are passed through.
+/// Makes "not found means default" kind of logic declarative.
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use std::io;
+/// use error_traits::{RecoverIf, RecoverIfDyn, WithContext};
+///
+/// let config = std::fs::read_to_string("missing/config.toml")
+///     .context("reading config")
+///     .recover_if::<io::Error>(|e| match e.kind() {
+///         io::ErrorKind::NotFound => String::new(),
+///         _ => "unreachable".to_owned(),
+///     });
+/// assert_eq!(config.unwrap(), "");
+///
+/// let boxed: Result<u16, Box<dyn Error + Send + Sync>> = Err("foo".parse::<u16>().unwrap_err().into());
+/// let number = boxed.recover_if::<std::num::ParseIntError>(|_| 0);
+/// assert_eq!(number.unwrap(), 0);
+/// ```
+pub trait RecoverIf<T>
+where
+    Self: Sized,
+{
+    fn recover_if<C>(self, f: impl FnOnce(&C) -> T) -> Self
+    where
+        C: Error + 'static;
+}
+
+impl<T, E> RecoverIf<T> for StdResult<T, E>
+where
+    E: Error + 'static,
+{
+    fn recover_if<C>(self, f: impl FnOnce(&C) -> T) -> Self
+    where
+        C: Error + 'static,
+    {
+        recover(self, |e| e.find_source::<C>().map(f))
+    }
+}
+
+/// Same as `RecoverIf`, but for boxed errors, which don't implement `Error` themselves.
+pub trait RecoverIfDyn<T>
+where
+    Self: Sized,
+{
+    fn recover_if<C>(self, f: impl FnOnce(&C) -> T) -> Self
+    where
+        C: Error + 'static;
+}
+
+impl<T> RecoverIfDyn<T> for StdResult<T, Box<dyn Error>> {
+    fn recover_if<C>(self, f: impl FnOnce(&C) -> T) -> Self
+    where
+        C: Error + 'static,
+    {
+        recover(self, |e| e.find_source::<C>().map(f))
+    }
+}
+
+impl<T> RecoverIfDyn<T> for StdResult<T, Box<dyn Error + Send + Sync>> {
+    fn recover_if<C>(self, f: impl FnOnce(&C) -> T) -> Self
+    where
+        C: Error + 'static,
+    {
+        recover(self, |e| e.find_source::<C>().map(f))
+    }
+}
+
+fn recover<T, E>(res: StdResult<T, E>, f: impl FnOnce(&E) -> Option<T>) -> StdResult<T, E> {
+    match res {
+        Err(e) => f(&e).ok_or(e),
+        ok => ok,
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{ErrorClass, ErrorKindClass};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::mpsc::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+
+/// Common error of all `std::sync::mpsc` operations.
+/// Values that failed to be sent are dropped, use `ChannelSendError` to keep them.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::mpsc;
+/// use error_traits::ChannelError;
+///
+/// let (tx, rx) = mpsc::channel::<u32>();
+/// assert_eq!(rx.try_recv().map_err(ChannelError::from), Err(ChannelError::Empty));
+///
+/// drop(rx);
+/// assert_eq!(tx.send(42).map_err(ChannelError::from), Err(ChannelError::Disconnected));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChannelError {
+    /// Other side of the channel is dropped.
+    Disconnected,
+    /// Nothing to receive yet.
+    Empty,
+    /// Bounded channel is full.
+    Full,
+    /// Nothing was received in time.
+    Timeout,
+}
+
+impl Display for ChannelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ChannelError::Disconnected => "channel is disconnected",
+            ChannelError::Empty => "channel is empty",
+            ChannelError::Full => "channel is full",
+            ChannelError::Timeout => "timed out waiting on channel",
+        })
+    }
+}
+
+impl Error for ChannelError {}
+
+impl ErrorClass for ChannelError {
+    fn class(&self) -> ErrorKindClass {
+        match self {
+            ChannelError::Disconnected => ErrorKindClass::Permanent,
+            ChannelError::Empty | ChannelError::Timeout => ErrorKindClass::Transient,
+            ChannelError::Full => ErrorKindClass::RateLimited,
+        }
+    }
+}
+
+impl<T> From<SendError<T>> for ChannelError {
+    fn from(_: SendError<T>) -> Self {
+        ChannelError::Disconnected
+    }
+}
+
+impl<T> From<TrySendError<T>> for ChannelError {
+    fn from(e: TrySendError<T>) -> Self {
+        ChannelError::from(&e)
+    }
+}
+
+impl<T> From<&TrySendError<T>> for ChannelError {
+    fn from(e: &TrySendError<T>) -> Self {
+        match e {
+            TrySendError::Full(_) => ChannelError::Full,
+            TrySendError::Disconnected(_) => ChannelError::Disconnected,
+        }
+    }
+}
+
+/// `ChannelError` of a failed send, along with the value that wasn't sent.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::mpsc;
+/// use error_traits::{ChannelError, ChannelSendError};
+///
+/// let (tx, rx) = mpsc::channel::<u32>();
+/// drop(rx);
+/// let error = ChannelSendError::from(tx.send(42).unwrap_err());
+/// assert_eq!(error.error(), ChannelError::Disconnected);
+/// assert_eq!(error.into_value(), 42);
+/// ```
+pub struct ChannelSendError<T> {
+    error: ChannelError,
+    value: T,
+}
+
+impl<T> ChannelSendError<T> {
+    pub fn error(&self) -> ChannelError {
+        self.error
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> std::fmt::Debug for ChannelSendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChannelSendError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for ChannelSendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<T> Error for ChannelSendError<T> {}
+
+impl<T> ErrorClass for ChannelSendError<T> {
+    fn class(&self) -> ErrorKindClass {
+        self.error.class()
+    }
+}
+
+impl<T> From<SendError<T>> for ChannelSendError<T> {
+    fn from(SendError(value): SendError<T>) -> Self {
+        let error = ChannelError::Disconnected;
+        Self { error, value }
+    }
+}
+
+impl<T> From<TrySendError<T>> for ChannelSendError<T> {
+    fn from(e: TrySendError<T>) -> Self {
+        let error = ChannelError::from(&e);
+        let (TrySendError::Full(value) | TrySendError::Disconnected(value)) = e;
+        Self { error, value }
+    }
+}
+
+impl<T> From<ChannelSendError<T>> for ChannelError {
+    fn from(e: ChannelSendError<T>) -> Self {
+        e.error
+    }
+}
+
+impl From<RecvError> for ChannelError {
+    fn from(_: RecvError) -> Self {
+        ChannelError::Disconnected
+    }
+}
+
+impl From<TryRecvError> for ChannelError {
+    fn from(e: TryRecvError) -> Self {
+        match e {
+            TryRecvError::Empty => ChannelError::Empty,
+            TryRecvError::Disconnected => ChannelError::Disconnected,
+        }
+    }
+}
+
+impl From<RecvTimeoutError> for ChannelError {
+    fn from(e: RecvTimeoutError) -> Self {
+        match e {
+            RecvTimeoutError::Timeout => ChannelError::Timeout,
+            RecvTimeoutError::Disconnected => ChannelError::Disconnected,
+        }
+    }
+}
+
+/// Sends a value, logging failure through `LogErr`.
+/// The value that wasn't sent is returned back in `ChannelSendError`.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::mpsc;
+/// use error_traits::{ChannelError, RecvOrLog, SendOrLog};
+///
+/// let (tx, rx) = mpsc::channel();
+/// tx.send_or_log(42, "worker: ").unwrap();
+/// assert_eq!(rx.recv_or_log("worker: "), Ok(42));
+///
+/// drop(tx);
+/// assert_eq!(rx.recv_or_log("worker: "), Err(ChannelError::Disconnected));
+///
+/// let (tx, rx) = mpsc::channel();
+/// drop(rx);
+/// let error = tx.send_or_log(43, "worker: ").unwrap_err();
+/// assert_eq!(error.into_value(), 43);
+/// ```
+#[cfg(feature = "log_err")]
+pub trait SendOrLog<T> {
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn send_or_log(&self, value: T, log_prefix: &str) -> crate::StdResult<(), ChannelSendError<T>>;
+}
+
+#[cfg(feature = "log_err")]
+impl<T> SendOrLog<T> for std::sync::mpsc::Sender<T> {
+    fn send_or_log(&self, value: T, log_prefix: &str) -> crate::StdResult<(), ChannelSendError<T>> {
+        use crate::{ErrInto, LogErr};
+
+        self.send(value).err_into().log_err(log_prefix)
+    }
+}
+
+#[cfg(feature = "log_err")]
+impl<T> SendOrLog<T> for std::sync::mpsc::SyncSender<T> {
+    fn send_or_log(&self, value: T, log_prefix: &str) -> crate::StdResult<(), ChannelSendError<T>> {
+        use crate::{ErrInto, LogErr};
+
+        self.send(value).err_into().log_err(log_prefix)
+    }
+}
+
+/// Receives a value, logging failure through `LogErr`. Counterpart of `SendOrLog`.
+/// Requires an initialized logger.
+#[cfg(feature = "log_err")]
+pub trait RecvOrLog<T> {
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn recv_or_log(&self, log_prefix: &str) -> crate::StdResult<T, ChannelError>;
+}
+
+#[cfg(feature = "log_err")]
+impl<T> RecvOrLog<T> for std::sync::mpsc::Receiver<T> {
+    fn recv_or_log(&self, log_prefix: &str) -> crate::StdResult<T, ChannelError> {
+        use crate::{ErrInto, LogErr};
+
+        self.recv().err_into().log_err(log_prefix)
+    }
+}
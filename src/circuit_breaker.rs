@@ -0,0 +1,175 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Short-circuits calls to an operation after `failure_threshold` consecutive failures.
+/// Once `cooldown` passes, a single probing call is let through:
+/// its success closes the circuit, its failure opens it again.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use error_traits::{CircuitBreaker, CircuitError, CircuitState};
+///
+/// let breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+/// for _ in 0..2 {
+///     let _ = breaker.call(|| "foo".parse::<u16>());
+/// }
+/// assert_eq!(breaker.state(), CircuitState::Open);
+/// assert!(matches!(breaker.call(|| "42".parse::<u16>()), Err(CircuitError::Open(_))));
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug)]
+enum Inner {
+    Closed { failures: u32 },
+    Open { since: Instant },
+    HalfOpen { since: Instant },
+}
+
+/// Observable state of `CircuitBreaker`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls go through.
+    Closed,
+    /// Calls are rejected with `CircuitOpen`.
+    Open,
+    /// Probing call is in progress, other calls are rejected.
+    HalfOpen,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            inner: Mutex::new(Inner::Closed { failures: 0 }),
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        match *self.lock() {
+            Inner::Closed { .. } => CircuitState::Closed,
+            Inner::Open { .. } => CircuitState::Open,
+            Inner::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Calls `f` unless circuit is open.
+    pub fn call<T, E>(&self, f: impl FnOnce() -> StdResult<T, E>) -> StdResult<T, CircuitError<E>> {
+        self.acquire()?;
+        let res = f();
+        self.record(res.is_ok());
+        res.map_err(CircuitError::Failed)
+    }
+
+    /// Awaits `fut` unless circuit is open.
+    pub async fn call_async<T, E>(
+        &self,
+        fut: impl Future<Output = StdResult<T, E>>,
+    ) -> StdResult<T, CircuitError<E>> {
+        self.acquire()?;
+        let res = fut.await;
+        self.record(res.is_ok());
+        res.map_err(CircuitError::Failed)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn acquire(&self) -> StdResult<(), CircuitOpen> {
+        let mut inner = self.lock();
+        match *inner {
+            Inner::Closed { .. } => Ok(()),
+            // Probing call of a half-open circuit might've been dropped
+            // without finishing, so new one is allowed after another cooldown.
+            Inner::Open { since } | Inner::HalfOpen { since }
+                if since.elapsed() >= self.cooldown =>
+            {
+                *inner = Inner::HalfOpen {
+                    since: Instant::now(),
+                };
+                Ok(())
+            }
+            Inner::Open { .. } | Inner::HalfOpen { .. } => Err(CircuitOpen),
+        }
+    }
+
+    fn record(&self, success: bool) {
+        let mut inner = self.lock();
+        *inner = match (&*inner, success) {
+            (_, true) => Inner::Closed { failures: 0 },
+            (Inner::Closed { failures }, false) if failures + 1 < self.failure_threshold => {
+                Inner::Closed {
+                    failures: failures + 1,
+                }
+            }
+            (_, false) => Inner::Open {
+                since: Instant::now(),
+            },
+        };
+    }
+}
+
+/// Returned by `CircuitBreaker` instead of calling the operation, while circuit is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitOpen;
+
+impl Display for CircuitOpen {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("circuit breaker is open")
+    }
+}
+
+impl Error for CircuitOpen {}
+
+/// Error of an operation called through `CircuitBreaker`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError<E> {
+    /// Operation wasn't called.
+    Open(CircuitOpen),
+    /// Operation was called and failed.
+    Failed(E),
+}
+
+impl<E> From<CircuitOpen> for CircuitError<E> {
+    fn from(open: CircuitOpen) -> Self {
+        Self::Open(open)
+    }
+}
+
+impl<E> Display for CircuitError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open(open) => Display::fmt(open, f),
+            Self::Failed(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl<E> Error for CircuitError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Open(_) => None,
+            Self::Failed(e) => Some(e),
+        }
+    }
+}
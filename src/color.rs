@@ -0,0 +1,76 @@
+use crate::{Chain, ChainExt};
+use std::fmt::{Display, Formatter};
+
+const RED_BOLD: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// Displays an error with all of its causes, colored and indented,
+/// see `DisplayPrettyExt::display_pretty`.
+#[derive(Debug, Clone)]
+pub struct DisplayPretty<'a> {
+    chain: Chain<'a>,
+    color: bool,
+}
+
+impl DisplayPretty<'_> {
+    /// Forces colors on or off, instead of checking `NO_COLOR`.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl Display for DisplayPretty<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (label, reset) = match self.color {
+            true => ((RED_BOLD, YELLOW), RESET),
+            false => (("", ""), ""),
+        };
+        for (i, e) in self.chain.clone().enumerate() {
+            match i {
+                0 => write!(f, "{}error:{reset} {e}", label.0)?,
+                _ => write!(
+                    f,
+                    "\n{:indent$}{}caused by:{reset} {e}",
+                    "",
+                    label.1,
+                    indent = i * 2
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renders an error and its causes for a terminal, meant for CLI tools
+/// printing errors from `main`. Colors are disabled when `NO_COLOR` is set.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{DisplayPrettyExt, WithContext};
+///
+/// let error = "foo".parse::<u16>().context("parsing port").unwrap_err();
+/// eprintln!("{}", error.display_pretty());
+///
+/// assert_eq!(
+///     error.display_pretty().with_color(false).to_string(),
+///     "error: parsing port\n  caused by: invalid digit found in string"
+/// );
+/// ```
+pub trait DisplayPrettyExt {
+    fn display_pretty(&self) -> DisplayPretty<'_>;
+}
+
+impl<E> DisplayPrettyExt for E
+where
+    E: ChainExt + ?Sized,
+{
+    fn display_pretty(&self) -> DisplayPretty<'_> {
+        DisplayPretty {
+            chain: self.chain(),
+            color: std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::StdResult;
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Error wrapped with a message describing what was being done when it occurred.
+/// Displays the context only (or `context: error` with `{:#}`), while wrapped
+/// error is available through `source()`.
+/// Static contexts are stored as is, without allocating.
+#[derive(Debug)]
+pub struct Contextualized<E> {
+    context: Cow<'static, str>,
+    error: E,
+}
+
+impl<E> Contextualized<E> {
+    pub fn new(context: impl Into<Cow<'static, str>>, error: E) -> Self {
+        Self {
+            context: context.into(),
+            error,
+        }
+    }
+
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for Contextualized<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}: {}", self.context, self.error)
+        } else {
+            f.write_str(&self.context)
+        }
+    }
+}
+
+impl<E> Error for Contextualized<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Short name of `Contextualized` for embedding into downstream error enums.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{Ctx, WithContext};
+/// use std::error::Error;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug)]
+/// enum ConfigError {
+///     Port(Ctx<ParseIntError>),
+/// }
+///
+/// impl From<Ctx<ParseIntError>> for ConfigError {
+///     fn from(e: Ctx<ParseIntError>) -> Self {
+///         ConfigError::Port(e)
+///     }
+/// }
+///
+/// fn port() -> Result<u16, ConfigError> {
+///     Ok("foo".parse::<u16>().context("parsing port")?)
+/// }
+///
+/// let ConfigError::Port(e) = port().unwrap_err();
+/// assert_eq!(format!("{e:#}"), "parsing port: invalid digit found in string");
+/// assert_eq!(e.context(), "parsing port");
+/// assert!(e.source().is_some());
+/// ```
+pub type Ctx<E> = Contextualized<E>;
+
+/// Wraps error (if any) into `Contextualized`, without pulling in `anyhow`.
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use error_traits::WithContext;
+///
+/// let path = "missing/config.toml";
+/// let error = std::fs::read_to_string(path)
+///     .with_context(|| format!("reading {path}"))
+///     .unwrap_err();
+/// assert_eq!(error.to_string(), "reading missing/config.toml");
+/// assert!(error.source().is_some());
+///
+/// let error = "foo".parse::<u16>().context("parsing port").unwrap_err();
+/// assert_eq!(format!("{error:#}"), "parsing port: invalid digit found in string");
+/// ```
+pub trait WithContext<T, E> {
+    fn context(self, context: impl Into<Cow<'static, str>>) -> StdResult<T, Contextualized<E>>;
+
+    fn with_context<C>(self, f: impl FnOnce() -> C) -> StdResult<T, Contextualized<E>>
+    where
+        C: Into<Cow<'static, str>>;
+}
+
+impl<T, E> WithContext<T, E> for StdResult<T, E>
+where
+    E: Error,
+{
+    fn context(self, context: impl Into<Cow<'static, str>>) -> StdResult<T, Contextualized<E>> {
+        self.map_err(|e| Contextualized::new(context, e))
+    }
+
+    fn with_context<C>(self, f: impl FnOnce() -> C) -> StdResult<T, Contextualized<E>>
+    where
+        C: Into<Cow<'static, str>>,
+    {
+        self.map_err(|e| Contextualized::new(f(), e))
+    }
+}
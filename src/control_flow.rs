@@ -0,0 +1,63 @@
+use crate::StdResult;
+use core::ops::ControlFlow;
+
+/// Turns `Result` into `ControlFlow`, breaking on error.
+/// Handy inside `try_fold`, visitors and other APIs speaking `ControlFlow`.
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use error_traits::{FromControlFlow, IntoControlFlow};
+///
+/// let flow = ["1", "2", "foo", "4"].iter().try_fold(0_u16, |sum, s| {
+///     s.parse::<u16>()
+///         .break_on_err_with(|e| e.to_string())
+///         .map_continue(|n| sum + n)
+/// });
+/// assert_eq!(flow, ControlFlow::Break("invalid digit found in string".to_owned()));
+///
+/// let result = Result::from_control_flow(flow);
+/// assert_eq!(result, Err::<u16, _>("invalid digit found in string".to_owned()));
+///
+/// let flow = "42".parse::<u16>().into_control_flow();
+/// assert_eq!(flow, ControlFlow::Continue(42));
+/// ```
+pub trait IntoControlFlow<T, E>
+where
+    Self: Sized,
+{
+    /// `Ok` continues, `Err` breaks.
+    fn into_control_flow(self) -> ControlFlow<E, T>;
+
+    /// Same as `into_control_flow`, but breaks with error mapped by `f`.
+    fn break_on_err_with<B>(self, f: impl FnOnce(E) -> B) -> ControlFlow<B, T>;
+}
+
+impl<T, E> IntoControlFlow<T, E> for StdResult<T, E> {
+    fn into_control_flow(self) -> ControlFlow<E, T> {
+        self.break_on_err_with(|e| e)
+    }
+
+    fn break_on_err_with<B>(self, f: impl FnOnce(E) -> B) -> ControlFlow<B, T> {
+        match self {
+            Ok(t) => ControlFlow::Continue(t),
+            Err(e) => ControlFlow::Break(f(e)),
+        }
+    }
+}
+
+/// Builds `Result` out of `ControlFlow`, treating `Break` as an error.
+/// Counterpart of `IntoControlFlow`.
+pub trait FromControlFlow<B, C> {
+    fn from_control_flow(flow: ControlFlow<B, C>) -> Self;
+}
+
+impl<B, C> FromControlFlow<B, C> for StdResult<C, B> {
+    fn from_control_flow(flow: ControlFlow<B, C>) -> Self {
+        match flow {
+            ControlFlow::Continue(c) => Ok(c),
+            ControlFlow::Break(b) => Err(b),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::StdResult;
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+thread_local! {
+    static CURRENT: RefCell<Vec<Arc<str>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the id even if the body panics.
+struct ScopeGuard;
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|ids| ids.borrow_mut().pop());
+    }
+}
+
+/// Error carrying the correlation id of the request (or job) it happened in.
+/// Displays as `[id] error`, so `LogErr`, `TraceErr` and others include the id
+/// without any changes. Displays as the error only, if there is no id.
+#[derive(Debug, Clone)]
+pub struct Correlated<E> {
+    id: Option<Arc<str>>,
+    error: E,
+}
+
+impl<E> Correlated<E> {
+    pub fn new(id: impl Into<Arc<str>>, error: E) -> Self {
+        Self {
+            id: Some(id.into()),
+            error,
+        }
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for Correlated<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.id {
+            Some(id) => write!(f, "[{id}] {}", self.error),
+            None => Display::fmt(&self.error, f),
+        }
+    }
+}
+
+impl<E> Error for Correlated<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Runs `body` with `id` as the current correlation id of this thread
+/// (see `current_correlation_id`). Scopes nest, the innermost one wins.
+/// Use `correlation_scope_async` for futures, as they may move between threads.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{correlation_scope, current_correlation_id};
+///
+/// assert_eq!(current_correlation_id(), None);
+/// correlation_scope("req-42", || {
+///     assert_eq!(current_correlation_id().as_deref(), Some("req-42"));
+/// });
+/// ```
+pub fn correlation_scope<R>(id: impl Into<Arc<str>>, body: impl FnOnce() -> R) -> R {
+    enter(id.into(), body)
+}
+
+fn enter<R>(id: Arc<str>, body: impl FnOnce() -> R) -> R {
+    CURRENT.with(|ids| ids.borrow_mut().push(id));
+    let _guard = ScopeGuard;
+    body()
+}
+
+/// Innermost correlation id set by `correlation_scope` or `correlation_scope_async`.
+pub fn current_correlation_id() -> Option<Arc<str>> {
+    CURRENT.with(|ids| ids.borrow().last().cloned())
+}
+
+/// Same as `correlation_scope`, but for a future: the id is current
+/// during every poll of `fut`, on whatever thread it happens.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{correlation_scope_async, WithCorrelationId};
+///
+/// async fn handle() -> Result<u16, error_traits::Correlated<std::num::ParseIntError>> {
+///     "foo".parse::<u16>().with_current_correlation_id()
+/// }
+///
+/// let res = futures::executor::block_on(correlation_scope_async("req-42", handle()));
+/// assert_eq!(res.unwrap_err().to_string(), "[req-42] invalid digit found in string");
+/// ```
+#[cfg(feature = "future_ext")]
+pub fn correlation_scope_async<Fut>(id: impl Into<Arc<str>>, fut: Fut) -> CorrelationScope<Fut>
+where
+    Fut: std::future::Future,
+{
+    CorrelationScope { id: id.into(), fut }
+}
+
+#[cfg(feature = "future_ext")]
+pin_project_lite::pin_project! {
+    /// Future for the `correlation_scope_async` function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CorrelationScope<Fut> {
+        id: Arc<str>,
+        #[pin]
+        fut: Fut,
+    }
+}
+
+#[cfg(feature = "future_ext")]
+impl<Fut> std::future::Future for CorrelationScope<Fut>
+where
+    Fut: std::future::Future,
+{
+    type Output = Fut::Output;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let this = self.project();
+        enter(this.id.clone(), || this.fut.poll(cx))
+    }
+}
+
+/// Wraps error (if any) into `Correlated`, either with the given id,
+/// or with the current one (see `correlation_scope`).
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{correlation_scope, WithCorrelationId};
+///
+/// let res = "foo".parse::<u16>().with_correlation_id("req-42");
+/// assert_eq!(res.unwrap_err().to_string(), "[req-42] invalid digit found in string");
+///
+/// let res = correlation_scope("req-43", || "foo".parse::<u16>().with_current_correlation_id());
+/// assert_eq!(res.unwrap_err().id(), Some("req-43"));
+///
+/// let res = "foo".parse::<u16>().with_current_correlation_id();
+/// assert_eq!(res.unwrap_err().to_string(), "invalid digit found in string");
+/// ```
+pub trait WithCorrelationId<T, E> {
+    fn with_correlation_id(self, id: impl Into<Arc<str>>) -> StdResult<T, Correlated<E>>;
+
+    fn with_current_correlation_id(self) -> StdResult<T, Correlated<E>>;
+}
+
+impl<T, E> WithCorrelationId<T, E> for StdResult<T, E> {
+    fn with_correlation_id(self, id: impl Into<Arc<str>>) -> StdResult<T, Correlated<E>> {
+        self.map_err(|error| Correlated::new(id, error))
+    }
+
+    fn with_current_correlation_id(self) -> StdResult<T, Correlated<E>> {
+        self.map_err(|error| Correlated {
+            id: current_correlation_id(),
+            error,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use crate::StdResult;
+
+/// If error is present, this trait logs it with `defmt::error!` and returns back.
+/// Counterpart of `LogErr` for embedded targets, works in `no_std`.
+/// Requires a `defmt` global logger.
+///
+/// # Examples
+///
+/// Not compiled as a doctest, since linking requires a `defmt` global logger.
+///
+/// ```ignore
+/// use error_traits::LogErrDefmt;
+///
+/// #[derive(defmt::Format)]
+/// enum SensorError {
+///     Timeout,
+/// }
+///
+/// let reading: Result<u16, SensorError> = Err(SensorError::Timeout);
+/// let reading = reading.log_err_defmt("sensor: ");
+/// ```
+pub trait LogErrDefmt {
+    fn log_err_defmt(self, log_prefix: &str) -> Self;
+}
+
+impl<T, E> LogErrDefmt for StdResult<T, E>
+where
+    E: defmt::Format,
+{
+    fn log_err_defmt(self, log_prefix: &str) -> Self {
+        if let Err(e) = &self {
+            defmt::error!("{=str}{}", log_prefix, e)
+        }
+        self
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{MergeInto, MergeOkErr, StdResult};
+use either::Either;
+
+// Same convention as `either` itself: `Left` is for errors, `Right` is for values.
+
+impl<T> MergeOkErr<T> for Either<T, T> {
+    fn merge_ok_err(self) -> T {
+        self.into_inner()
+    }
+}
+
+impl<L, R> MergeInto<R, L> for Either<L, R> {
+    fn merge_into<U>(self) -> U
+    where
+        R: Into<U>,
+        L: Into<U>,
+    {
+        self.either_into()
+    }
+}
+
+/// Turns `Result` into `Either`, with error on the `Left` side.
+///
+/// # Examples
+///
+/// ```
+/// use either::Either;
+/// use error_traits::{EitherIntoResult, MergeOkErr, ResultIntoEither};
+///
+/// let either = "foo".parse::<u16>().map_err(|_| 0).into_either();
+/// assert_eq!(either, Either::Left(0));
+/// assert_eq!(either.merge_ok_err(), 0);
+///
+/// let result: Result<u16, String> = Either::Right(42).into_result();
+/// assert_eq!(result, Ok(42));
+/// ```
+pub trait ResultIntoEither<T, E> {
+    fn into_either(self) -> Either<E, T>;
+}
+
+impl<T, E> ResultIntoEither<T, E> for StdResult<T, E> {
+    fn into_either(self) -> Either<E, T> {
+        match self {
+            Ok(t) => Either::Right(t),
+            Err(e) => Either::Left(e),
+        }
+    }
+}
+
+/// Turns `Either` into `Result`, treating `Left` as an error.
+/// Counterpart of `ResultIntoEither`.
+pub trait EitherIntoResult<L, R> {
+    fn into_result(self) -> StdResult<R, L>;
+}
+
+impl<L, R> EitherIntoResult<L, R> for Either<L, R> {
+    fn into_result(self) -> StdResult<R, L> {
+        match self {
+            Either::Left(l) => Err(l),
+            Either::Right(r) => Ok(r),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::StdResult;
+use std::error::Error;
+
+/// Erases error type, which is the usual thing to do at an API boundary.
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use error_traits::ErrBoxed;
+///
+/// fn port() -> Result<u16, Box<dyn Error + Send + Sync>> {
+///     "foo".parse::<u16>().err_boxed()
+/// }
+///
+/// let local: Result<u16, Box<dyn Error>> = "foo".parse::<u16>().err_boxed_local();
+/// ```
+pub trait ErrBoxed<T, E> {
+    fn err_boxed(self) -> StdResult<T, Box<dyn Error + Send + Sync>>
+    where
+        E: Error + Send + Sync + 'static;
+
+    /// Same as `err_boxed`, but for errors that are not `Send` or `Sync`.
+    fn err_boxed_local(self) -> StdResult<T, Box<dyn Error>>
+    where
+        E: Error + 'static;
+}
+
+impl<T, E> ErrBoxed<T, E> for StdResult<T, E> {
+    fn err_boxed(self) -> StdResult<T, Box<dyn Error + Send + Sync>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.map_err(|e| Box::new(e) as _)
+    }
+
+    fn err_boxed_local(self) -> StdResult<T, Box<dyn Error>>
+    where
+        E: Error + 'static,
+    {
+        self.map_err(|e| Box::new(e) as _)
+    }
+}
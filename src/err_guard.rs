@@ -0,0 +1,97 @@
+use crate::log_err::{log_err_from, TARGET};
+use crate::StdResult;
+use std::fmt::Display;
+use std::panic::Location;
+
+/// `Result` that logs its error if dropped without being taken out of it
+/// in debug builds, see `GuardErr::guard_err`.
+#[derive(Debug)]
+#[must_use = "dropping the guard logs the error, call `into_result` to handle it"]
+pub struct ErrGuard<T, E>
+where
+    E: Display,
+{
+    result: Option<StdResult<T, E>>,
+    log_prefix: String,
+    location: &'static Location<'static>,
+}
+
+impl<T, E> ErrGuard<T, E>
+where
+    E: Display,
+{
+    /// Takes the result out, disarming the guard.
+    pub fn into_result(mut self) -> StdResult<T, E> {
+        self.result
+            .take()
+            .expect("result is only taken on consumption")
+    }
+
+    pub fn as_result(&self) -> &StdResult<T, E> {
+        self.result
+            .as_ref()
+            .expect("result is only taken on consumption")
+    }
+}
+
+impl<T, E> Drop for ErrGuard<T, E>
+where
+    E: Display,
+{
+    fn drop(&mut self) {
+        // Logging silently dropped errors is a debugging aid, like `debug_assert!`.
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        if let Some(res) = self.result.take() {
+            // With `log_location` feature, the location is prepended by `emit` already.
+            #[cfg(feature = "log_location")]
+            let prefix = || format!("unhandled error: {}", self.log_prefix);
+            #[cfg(not(feature = "log_location"))]
+            let prefix = || format!("[{}] unhandled error: {}", self.location, self.log_prefix);
+            let _ = log_err_from(res, self.location, TARGET, log::Level::Error, prefix);
+        }
+    }
+}
+
+/// Wraps `Result` into `ErrGuard`, which logs the error (along with the place
+/// it was guarded at) if it's silently dropped, like with `let _ = fallible();`.
+/// Only logs in builds with `debug_assertions` enabled, release builds drop the error silently.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::GuardErr;
+///
+/// fn fallible() -> Result<u16, std::num::ParseIntError> {
+///     "foo".parse::<u16>()
+/// }
+///
+/// // Dropped without being looked at, so the error is logged (in debug builds).
+/// let _ = fallible().guard_err("parsing port: ");
+///
+/// // Handled, nothing is logged.
+/// let port = fallible().guard_err("parsing port: ").into_result().unwrap_or(8080);
+/// ```
+pub trait GuardErr<T, E>
+where
+    E: Display,
+{
+    #[track_caller]
+    fn guard_err(self, log_prefix: &str) -> ErrGuard<T, E>;
+}
+
+impl<T, E> GuardErr<T, E> for StdResult<T, E>
+where
+    E: Display,
+{
+    #[track_caller]
+    fn guard_err(self, log_prefix: &str) -> ErrGuard<T, E> {
+        ErrGuard {
+            result: Some(self),
+            log_prefix: log_prefix.to_owned(),
+            location: Location::caller(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::StdResult;
+
+type Callback<'a, E> = Box<dyn FnOnce(&E) + 'a>;
+
+/// Callbacks registered inside `scope_try`, run if the scope fails.
+pub struct ErrScope<'a, E> {
+    callbacks: Vec<Callback<'a, E>>,
+}
+
+impl<'a, E> ErrScope<'a, E> {
+    /// Registers `f` to be called with the error, if the scope ends with one,
+    /// whether it's returned explicitly or propagated with `?`.
+    /// Callbacks run in reverse order of registration, like destructors.
+    pub fn on_scope_err(&mut self, f: impl FnOnce(&E) + 'a) {
+        self.callbacks.push(Box::new(f))
+    }
+}
+
+/// Runs `body`, calling callbacks it registered with `ErrScope::on_scope_err`
+/// if it fails. Keeps rollback logic next to the step it rolls back.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use error_traits::scope_try;
+///
+/// let log = RefCell::new(Vec::new());
+/// let result = scope_try(|scope| -> Result<u16, std::num::ParseIntError> {
+///     log.borrow_mut().push("created temp file");
+///     scope.on_scope_err(|_| log.borrow_mut().push("removed temp file"));
+///
+///     let port = "foo".parse::<u16>()?;
+///     log.borrow_mut().push("bound port");
+///     Ok(port)
+/// });
+/// assert!(result.is_err());
+/// assert_eq!(*log.borrow(), ["created temp file", "removed temp file"]);
+/// ```
+pub fn scope_try<'a, T, E>(
+    body: impl FnOnce(&mut ErrScope<'a, E>) -> StdResult<T, E>,
+) -> StdResult<T, E> {
+    let mut scope = ErrScope {
+        callbacks: Vec::new(),
+    };
+    let res = body(&mut scope);
+    if let Err(e) = &res {
+        for f in scope.callbacks.into_iter().rev() {
+            f(e)
+        }
+    }
+    res
+}
@@ -0,0 +1,177 @@
+use log::Level;
+use std::error::Error;
+use std::fmt::{Arguments, Display, Formatter};
+use std::panic::Location;
+use std::sync::OnceLock;
+
+static SINK: OnceLock<Box<dyn ErrSink>> = OnceLock::new();
+
+/// Destination of messages emitted by `LogErr` and other logging traits of `log_err` feature.
+/// Defaults to `LogSink`, install another one with `set_global_sink` to route
+/// errors to telemetry pipeline, test capture buffer, file, etc.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Arguments;
+/// use error_traits::{set_global_sink, ErrSink, LogErr};
+///
+/// struct Stderr;
+///
+/// impl ErrSink for Stderr {
+///     fn emit(&self, target: &str, level: log::Level, msg: Arguments<'_>) {
+///         eprintln!("[{level} {target}] {msg}")
+///     }
+/// }
+///
+/// set_global_sink(Stderr).unwrap();
+/// let error = "foo".parse::<u16>().log_err("parsing port: ");
+/// ```
+pub trait ErrSink: Send + Sync {
+    /// Whether messages of `level` under `target` would be emitted at all.
+    /// Allows skipping formatting of messages that would be discarded.
+    fn enabled(&self, _target: &str, _level: Level) -> bool {
+        true
+    }
+
+    fn emit(&self, target: &str, level: Level, msg: Arguments<'_>);
+
+    /// Same as `emit`, but with location of the call that logs the error
+    /// (only known with `log_location` feature). Calls `emit` by default.
+    fn emit_at(
+        &self,
+        location: Option<&'static Location<'static>>,
+        target: &str,
+        level: Level,
+        msg: Arguments<'_>,
+    ) {
+        let _ = location;
+        self.emit(target, level, msg)
+    }
+
+    /// Same as `emit_at`, but with structured fields attached, used by `LogErrKv`.
+    /// By default, fields are appended to the message as ` key=value` pairs.
+    #[cfg(feature = "log_err_kv")]
+    fn emit_kv(
+        &self,
+        location: Option<&'static Location<'static>>,
+        target: &str,
+        level: Level,
+        msg: Arguments<'_>,
+        kvs: &dyn log::kv::Source,
+    ) {
+        self.emit_at(
+            location,
+            target,
+            level,
+            format_args!("{msg}{}", KvPairs(kvs)),
+        )
+    }
+}
+
+/// Formats fields as ` key=value` pairs.
+#[cfg(feature = "log_err_kv")]
+struct KvPairs<'a>(&'a dyn log::kv::Source);
+
+#[cfg(feature = "log_err_kv")]
+impl Display for KvPairs<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use log::kv::{Key, Value, VisitSource};
+
+        struct Visitor<'a, 'b>(&'a mut Formatter<'b>);
+
+        impl<'kvs> VisitSource<'kvs> for Visitor<'_, '_> {
+            fn visit_pair(
+                &mut self,
+                key: Key<'kvs>,
+                value: Value<'kvs>,
+            ) -> Result<(), log::kv::Error> {
+                write!(self.0, " {key}={value}").map_err(Into::into)
+            }
+        }
+
+        self.0.visit(&mut Visitor(f)).map_err(|_| std::fmt::Error)
+    }
+}
+
+/// Default sink, forwarding messages to the `log` crate.
+/// Records carry file and line of the call that logs the error,
+/// if it's known (see `ErrSink::emit_at`).
+/// Requires an initialized logger.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogSink;
+
+impl ErrSink for LogSink {
+    fn enabled(&self, target: &str, level: Level) -> bool {
+        log::log_enabled!(target: target, level)
+    }
+
+    fn emit(&self, target: &str, level: Level, msg: Arguments<'_>) {
+        self.emit_at(None, target, level, msg)
+    }
+
+    fn emit_at(
+        &self,
+        location: Option<&'static Location<'static>>,
+        target: &str,
+        level: Level,
+        msg: Arguments<'_>,
+    ) {
+        log::logger().log(
+            &log::Record::builder()
+                .args(msg)
+                .level(level)
+                .target(target)
+                .file_static(location.map(Location::file))
+                .line(location.map(Location::line))
+                .build(),
+        )
+    }
+
+    #[cfg(feature = "log_err_kv")]
+    fn emit_kv(
+        &self,
+        location: Option<&'static Location<'static>>,
+        target: &str,
+        level: Level,
+        msg: Arguments<'_>,
+        kvs: &dyn log::kv::Source,
+    ) {
+        log::logger().log(
+            &log::Record::builder()
+                .args(msg)
+                .level(level)
+                .target(target)
+                .file_static(location.map(Location::file))
+                .line(location.map(Location::line))
+                .key_values(kvs)
+                .build(),
+        )
+    }
+}
+
+/// Installs global sink, used instead of `LogSink`.
+/// Can only be called once, subsequent calls fail.
+pub fn set_global_sink(sink: impl ErrSink + 'static) -> Result<(), SetSinkError> {
+    SINK.set(Box::new(sink)).map_err(|_| SetSinkError)
+}
+
+/// Returns global sink if it was installed, `LogSink` otherwise.
+pub fn global_sink() -> &'static dyn ErrSink {
+    match SINK.get() {
+        Some(sink) => &**sink,
+        None => &LogSink,
+    }
+}
+
+/// Returned by `set_global_sink` if global sink is already installed.
+#[derive(Debug)]
+pub struct SetSinkError;
+
+impl Display for SetSinkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("error sink is already installed")
+    }
+}
+
+impl Error for SetSinkError {}
@@ -1,8 +1,37 @@
-use error_stack::{Context, IntoReportCompat, ResultExt};
-use std::fmt::{Debug, Display};
+use error_stack::{AttachmentKind, Context, Frame, FrameKind, IntoReportCompat, Report, ResultExt};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::panic::Location;
 
 /// Conversion and adjustment of error reports (after turning usual result to
 /// report by calling `.into_report()`).
+/// `_lazy` variants only build context and attachments on the error path
+/// (for lazy attachments alone use `ResultExt::attach_lazy` and
+/// `ResultExt::attach_printable_lazy`).
+///
+/// # Examples
+///
+/// ```
+/// use error_stack::Report;
+/// use error_traits::ConvReport;
+///
+/// #[derive(Debug)]
+/// struct ParseConfigError;
+///
+/// impl std::fmt::Display for ParseConfigError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str("failed to parse config")
+///     }
+/// }
+///
+/// impl error_stack::Context for ParseConfigError {}
+///
+/// let res = "foo"
+///     .parse::<u16>()
+///     .map_err(Report::from)
+///     .conv_to_and_attach_lazy(|| ParseConfigError, || format!("key: {}", "port"))
+///     .attach_printable_lazy_with_location(|| "while loading settings");
+/// ```
 pub trait ConvReport
 where
     Self: ResultExt + Sized,
@@ -22,6 +51,40 @@ where
         self.change_context_lazy(|| convert_to)
             .attach_printable_lazy(|| attach)
     }
+
+    fn conv_to_lazy<C>(self, convert_to: impl FnOnce() -> C) -> error_stack::Result<Self::Ok, C>
+    where
+        C: Context,
+    {
+        self.change_context_lazy(convert_to)
+    }
+
+    fn conv_to_and_attach_lazy<C, A>(
+        self,
+        convert_to: impl FnOnce() -> C,
+        attach: impl FnOnce() -> A,
+    ) -> error_stack::Result<Self::Ok, C>
+    where
+        A: Display + Debug + Send + Sync + 'static,
+        C: Context,
+    {
+        self.change_context_lazy(convert_to)
+            .attach_printable_lazy(attach)
+    }
+
+    /// Same as `ResultExt::attach_printable_lazy`, but appends `file:line:column`
+    /// of the call to the attachment.
+    #[track_caller]
+    fn attach_printable_lazy_with_location<A>(
+        self,
+        attach: impl FnOnce() -> A,
+    ) -> error_stack::Result<Self::Ok, Self::Context>
+    where
+        A: Display,
+    {
+        let location = Location::caller();
+        self.attach_printable_lazy(|| format!("{} (at {location})", attach()))
+    }
 }
 
 impl<T, C> ConvReport for error_stack::Result<T, C>
@@ -31,6 +94,126 @@ where
 {
 }
 
+/// Counterparts of `MapErrBy` and `MapErrToString` for reports.
+///
+/// # Examples
+///
+/// ```
+/// use error_stack::Report;
+/// use error_traits::ChangeContextBy;
+///
+/// #[derive(Debug)]
+/// struct ParseConfigError;
+///
+/// impl std::fmt::Display for ParseConfigError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str("failed to parse config")
+///     }
+/// }
+///
+/// impl error_stack::Context for ParseConfigError {}
+///
+/// let error = || ParseConfigError;
+/// let res = "foo".parse::<u16>().map_err(Report::from).change_context_by(error);
+/// assert_eq!(
+///     res.change_context_to_str(),
+///     Err("failed to parse config: invalid digit found in string".to_owned())
+/// );
+/// ```
+pub trait ChangeContextBy<T, C> {
+    fn change_context_by<N>(self, f: impl FnOnce() -> N) -> error_stack::Result<T, N>
+    where
+        N: Context;
+
+    /// Turns report into a string with all of its contexts.
+    fn change_context_to_str(self) -> Result<T, String>;
+}
+
+impl<T, C> ChangeContextBy<T, C> for error_stack::Result<T, C>
+where
+    C: Context,
+{
+    fn change_context_by<N>(self, f: impl FnOnce() -> N) -> error_stack::Result<T, N>
+    where
+        N: Context,
+    {
+        self.change_context_lazy(f)
+    }
+
+    fn change_context_to_str(self) -> Result<T, String> {
+        self.map_err(|report| format!("{report:#}"))
+    }
+}
+
+/// Merging of failed results, so that no report is lost.
+///
+/// # Examples
+///
+/// ```
+/// use error_stack::Report;
+/// use error_traits::{CollectReports, ExtendReport};
+///
+/// let parse = |s: &str| s.parse::<u16>().map_err(Report::from);
+///
+/// let all = vec![parse("1"), parse("foo"), parse("bar")];
+/// let all: error_stack::Result<Vec<u16>, _> = all.into_iter().try_collect_reports();
+/// assert_eq!(all.unwrap_err().current_frames().len(), 2);
+///
+/// let both = parse("foo").extend_with(parse("2"));
+/// assert!(both.is_err());
+/// ```
+pub trait ExtendReport<T, C> {
+    /// Pairs values if both results are `Ok`, otherwise returns
+    /// all errors merged with `Report::extend_one`.
+    fn extend_with<U>(self, other: error_stack::Result<U, C>) -> error_stack::Result<(T, U), C>;
+}
+
+impl<T, C> ExtendReport<T, C> for error_stack::Result<T, C> {
+    fn extend_with<U>(self, other: error_stack::Result<U, C>) -> error_stack::Result<(T, U), C> {
+        match (self, other) {
+            (Ok(t), Ok(u)) => Ok((t, u)),
+            (Err(report), Ok(_)) | (Ok(_), Err(report)) => Err(report),
+            (Err(mut report), Err(other)) => {
+                report.extend_one(other);
+                Err(report)
+            }
+        }
+    }
+}
+
+/// Same as `ExtendReport`, but for iterators over results.
+pub trait CollectReports<T, C>
+where
+    Self: Iterator<Item = error_stack::Result<T, C>> + Sized,
+{
+    /// Collects values if every result is `Ok`, otherwise returns
+    /// all errors merged with `Report::extend_one`.
+    fn try_collect_reports<B>(self) -> error_stack::Result<B, C>
+    where
+        B: FromIterator<T>,
+    {
+        let mut merged: Option<Report<C>> = None;
+        let collected = self
+            .filter_map(|res| match res {
+                Ok(t) => Some(t),
+                Err(report) => {
+                    match &mut merged {
+                        Some(merged) => merged.extend_one(report),
+                        None => merged = Some(report),
+                    }
+                    None
+                }
+            })
+            .collect();
+        match merged {
+            Some(report) => Err(report),
+            None => Ok(collected),
+        }
+    }
+}
+
+impl<I, T, C> CollectReports<T, C> for I where I: Iterator<Item = error_stack::Result<T, C>> {}
+
 /// Create report from usual result ( required for `Box(dyn Error)` ).
 pub trait IntoReportDyn {
     type Ok;
@@ -51,3 +234,154 @@ where
             .into_report()
     }
 }
+
+/// Conversion of a report to `Box<dyn Error + Send + Sync>` for std-`Error`-based callers.
+/// Unlike `From<Report<C>>` implemented by `error_stack`, every context of
+/// the report becomes a link of `source()` chain, with printable attachments
+/// shown next to the context they were attached to.
+/// Merged reports can't form a single chain, so their chains are listed
+/// in `Display` of the context above them (or of the error itself, if none).
+///
+/// # Examples
+///
+/// ```
+/// use error_stack::{Report, ResultExt};
+/// use error_traits::{ChainExt, ReportIntoDyn};
+///
+/// #[derive(Debug)]
+/// struct ParseConfigError;
+///
+/// impl std::fmt::Display for ParseConfigError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str("failed to parse config")
+///     }
+/// }
+///
+/// impl error_stack::Context for ParseConfigError {}
+///
+/// let res: Result<u16, Box<dyn std::error::Error + Send + Sync>> = "foo"
+///     .parse::<u16>()
+///     .map_err(Report::from)
+///     .change_context(ParseConfigError)
+///     .attach_printable("key: port")
+///     .map_err(ReportIntoDyn::into_dyn_error);
+/// let error = res.unwrap_err();
+/// assert_eq!(error.to_string(), "failed to parse config (key: port)");
+/// assert_eq!(error.chain().count(), 2);
+///
+/// let mut report = Report::new("foo".parse::<u16>().unwrap_err()).attach_printable("key: port");
+/// report.extend_one(Report::new("".parse::<u16>().unwrap_err()));
+/// let error = report.change_context(ParseConfigError).into_dyn_error();
+/// assert_eq!(
+///     error.to_string(),
+///     "failed to parse config [invalid digit found in string (key: port); \
+///         cannot parse integer from empty string]"
+/// );
+/// assert_eq!(error.chain().count(), 1);
+/// ```
+pub trait ReportIntoDyn {
+    fn into_dyn_error(self) -> Box<dyn Error + Send + Sync>;
+}
+
+impl<C> ReportIntoDyn for Report<C> {
+    fn into_dyn_error(self) -> Box<dyn Error + Send + Sync> {
+        let mut roots = report_links(self.current_frames(), Vec::new());
+        if roots.len() == 1 {
+            Box::new(roots.remove(0))
+        } else {
+            Box::new(ReportBranches(roots))
+        }
+    }
+}
+
+/// Converts frames into links, giving each context the printable attachments
+/// found above it in its own branch.
+fn report_links(frames: &[Frame], attachments: Vec<String>) -> Vec<ReportLink> {
+    frames
+        .iter()
+        .flat_map(|frame| {
+            let mut attachments = attachments.clone();
+            match frame.kind() {
+                FrameKind::Context(context) => {
+                    return vec![ReportLink {
+                        context: context.to_string(),
+                        attachments,
+                        sources: report_links(frame.sources(), Vec::new()),
+                    }]
+                }
+                FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
+                    attachments.push(attachment.to_string())
+                }
+                FrameKind::Attachment(_) => (),
+            }
+            report_links(frame.sources(), attachments)
+        })
+        .collect()
+}
+
+/// Single context of a report, converted by `ReportIntoDyn`.
+/// If the context has several sources (reports merged with `extend_one`),
+/// `Display` lists their chains in brackets, and `source()` is `None`,
+/// since they can't be represented as a single chain.
+#[derive(Debug)]
+struct ReportLink {
+    context: String,
+    attachments: Vec<String>,
+    sources: Vec<ReportLink>,
+}
+
+impl ReportLink {
+    fn fmt_chain(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)?;
+        match self.sources.as_slice() {
+            [source] => {
+                f.write_str(": ")?;
+                source.fmt_chain(f)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Display for ReportLink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.context)?;
+        if !self.attachments.is_empty() {
+            write!(f, " ({})", self.attachments.join(", "))?;
+        }
+        if self.sources.len() > 1 {
+            write!(f, " [{}]", ReportBranches(&self.sources))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ReportLink {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.sources.as_slice() {
+            [source] => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Chains of several reports merged with `extend_one`, separated with `; `.
+#[derive(Debug)]
+struct ReportBranches<L>(L);
+
+impl<L> Display for ReportBranches<L>
+where
+    L: AsRef<[ReportLink]>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, link) in self.0.as_ref().iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            link.fmt_chain(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ReportBranches<Vec<ReportLink>> {}
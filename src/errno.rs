@@ -0,0 +1,97 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+/// Typed `errno` value, displayed the way the OS describes it.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use error_traits::{Errno, MapErrToErrno};
+///
+/// let errno = std::fs::read("missing/config.toml").map_err_to_errno().unwrap_err();
+/// assert_eq!(errno, Errno::from_errno(libc::ENOENT));
+///
+/// let not_found = io::Error::from(io::ErrorKind::NotFound);
+/// assert_eq!(Errno::from(&not_found).raw(), libc::ENOENT);
+///
+/// let error = io::Error::from(Errno::from_errno(libc::EACCES));
+/// assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Errno(i32);
+
+impl Errno {
+    pub fn from_errno(errno: i32) -> Self {
+        Self(errno)
+    }
+
+    /// Current value of `errno` for this thread.
+    pub fn last() -> Self {
+        Self::from(&io::Error::last_os_error())
+    }
+
+    pub fn raw(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Display for Errno {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&io::Error::from_raw_os_error(self.0), f)
+    }
+}
+
+impl Error for Errno {}
+
+impl From<&io::Error> for Errno {
+    /// Raw OS error, if there is one, or the closest `errno` to error's kind.
+    fn from(error: &io::Error) -> Self {
+        use io::ErrorKind::*;
+
+        if let Some(errno) = error.raw_os_error() {
+            return Self(errno);
+        }
+        Self(match error.kind() {
+            NotFound => libc::ENOENT,
+            PermissionDenied => libc::EACCES,
+            AlreadyExists => libc::EEXIST,
+            InvalidInput => libc::EINVAL,
+            TimedOut => libc::ETIMEDOUT,
+            Interrupted => libc::EINTR,
+            WouldBlock => libc::EWOULDBLOCK,
+            BrokenPipe => libc::EPIPE,
+            ConnectionRefused => libc::ECONNREFUSED,
+            ConnectionReset => libc::ECONNRESET,
+            AddrInUse => libc::EADDRINUSE,
+            OutOfMemory => libc::ENOMEM,
+            Unsupported => libc::ENOSYS,
+            _ => libc::EIO,
+        })
+    }
+}
+
+impl From<io::Error> for Errno {
+    fn from(error: io::Error) -> Self {
+        Self::from(&error)
+    }
+}
+
+impl From<Errno> for io::Error {
+    fn from(errno: Errno) -> Self {
+        io::Error::from_raw_os_error(errno.0)
+    }
+}
+
+/// Turns `io::Error` into `Errno`, for code that stays close to syscalls.
+pub trait MapErrToErrno<T> {
+    fn map_err_to_errno(self) -> StdResult<T, Errno>;
+}
+
+impl<T> MapErrToErrno<T> for StdResult<T, io::Error> {
+    fn map_err_to_errno(self) -> StdResult<T, Errno> {
+        self.map_err(Errno::from)
+    }
+}
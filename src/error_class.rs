@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::sync::mpsc::{RecvTimeoutError, TryRecvError};
+
+/// Broad class of an error, telling whether it makes sense to try again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKindClass {
+    /// Operation may succeed if retried.
+    Transient,
+    /// Operation may succeed if retried later, after backing off.
+    RateLimited,
+    /// Retrying won't help.
+    Permanent,
+    /// Nothing is known about the error.
+    Unknown,
+}
+
+/// Classifies an error as transient, permanent, etc.
+/// Consulted by `RetryOnErr::retry_transient` to retry only errors worth retrying.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use error_traits::{ErrorClass, ErrorKindClass};
+///
+/// let error = io::Error::from(io::ErrorKind::TimedOut);
+/// assert_eq!(error.class(), ErrorKindClass::Transient);
+/// assert!(error.is_transient());
+/// ```
+pub trait ErrorClass {
+    fn class(&self) -> ErrorKindClass;
+
+    /// Whether error is `Transient` or `RateLimited`.
+    fn is_transient(&self) -> bool {
+        matches!(
+            self.class(),
+            ErrorKindClass::Transient | ErrorKindClass::RateLimited
+        )
+    }
+}
+
+impl ErrorClass for io::ErrorKind {
+    fn class(&self) -> ErrorKindClass {
+        use io::ErrorKind::*;
+
+        match self {
+            TimedOut | WouldBlock | Interrupted | ConnectionReset | ConnectionAborted
+            | ConnectionRefused | NotConnected | BrokenPipe | AddrInUse | UnexpectedEof => {
+                ErrorKindClass::Transient
+            }
+            NotFound | PermissionDenied | AlreadyExists | InvalidInput | InvalidData
+            | Unsupported | AddrNotAvailable | OutOfMemory => ErrorKindClass::Permanent,
+            _ => ErrorKindClass::Unknown,
+        }
+    }
+}
+
+impl ErrorClass for io::Error {
+    fn class(&self) -> ErrorKindClass {
+        self.kind().class()
+    }
+}
+
+impl ErrorClass for RecvTimeoutError {
+    fn class(&self) -> ErrorKindClass {
+        match self {
+            RecvTimeoutError::Timeout => ErrorKindClass::Transient,
+            RecvTimeoutError::Disconnected => ErrorKindClass::Permanent,
+        }
+    }
+}
+
+impl ErrorClass for TryRecvError {
+    fn class(&self) -> ErrorKindClass {
+        match self {
+            TryRecvError::Empty => ErrorKindClass::Transient,
+            TryRecvError::Disconnected => ErrorKindClass::Permanent,
+        }
+    }
+}
+
+impl<E> ErrorClass for &E
+where
+    E: ErrorClass + ?Sized,
+{
+    fn class(&self) -> ErrorKindClass {
+        (**self).class()
+    }
+}
+
+/// Error paired with its `ErrorKindClass`, computed once, for example
+/// by `ErrPipeline::classify`. Displays and chains as the wrapped error.
+#[derive(Debug, Clone)]
+pub struct Classified<E> {
+    class: ErrorKindClass,
+    error: E,
+}
+
+impl<E> Classified<E>
+where
+    E: ErrorClass,
+{
+    pub fn new(error: E) -> Self {
+        Self {
+            class: error.class(),
+            error,
+        }
+    }
+}
+
+impl<E> Classified<E> {
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> ErrorClass for Classified<E> {
+    fn class(&self) -> ErrorKindClass {
+        self.class
+    }
+}
+
+impl<E> Display for Classified<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E> Error for Classified<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
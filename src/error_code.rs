@@ -0,0 +1,124 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Stable, machine-readable code of an error, for support teams and clients
+/// that shouldn't have to parse messages.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::ErrorCode;
+///
+/// enum DbError {
+///     Timeout,
+///     Corrupted,
+/// }
+///
+/// impl ErrorCode for DbError {
+///     fn code(&self) -> &'static str {
+///         match self {
+///             DbError::Timeout => "DB_TIMEOUT",
+///             DbError::Corrupted => "DB_CORRUPTED",
+///         }
+///     }
+///
+///     fn numeric_code(&self) -> Option<i32> {
+///         Some(match self {
+///             DbError::Timeout => 1001,
+///             DbError::Corrupted => 1002,
+///         })
+///     }
+/// }
+///
+/// assert_eq!(DbError::Timeout.code(), "DB_TIMEOUT");
+/// assert_eq!(DbError::Corrupted.numeric_code(), Some(1002));
+/// ```
+pub trait ErrorCode {
+    fn code(&self) -> &'static str;
+
+    /// Numeric counterpart of `code`, if error has one.
+    fn numeric_code(&self) -> Option<i32> {
+        None
+    }
+}
+
+impl<E> ErrorCode for &E
+where
+    E: ErrorCode + ?Sized,
+{
+    fn code(&self) -> &'static str {
+        (**self).code()
+    }
+
+    fn numeric_code(&self) -> Option<i32> {
+        (**self).numeric_code()
+    }
+}
+
+/// Error with a code attached by `WithCode::map_err_with_code`.
+/// Displays as the wrapped error, while `source()` is delegated to it.
+#[derive(Debug)]
+pub struct Coded<E> {
+    code: &'static str,
+    error: E,
+}
+
+impl<E> Coded<E> {
+    pub fn new(code: &'static str, error: E) -> Self {
+        Self { code, error }
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> ErrorCode for Coded<E> {
+    fn code(&self) -> &'static str {
+        self.code
+    }
+}
+
+impl<E> Display for Coded<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E> Error for Coded<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Attaches a code to errors that don't have one.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{ErrorCode, WithCode};
+///
+/// let error = "foo".parse::<u16>().map_err_with_code("E_PORT").unwrap_err();
+/// assert_eq!(error.code(), "E_PORT");
+/// assert_eq!(error.to_string(), "invalid digit found in string");
+/// ```
+pub trait WithCode<T, E> {
+    fn map_err_with_code(self, code: &'static str) -> StdResult<T, Coded<E>>;
+}
+
+impl<T, E> WithCode<T, E> for StdResult<T, E> {
+    fn map_err_with_code(self, code: &'static str) -> StdResult<T, Coded<E>> {
+        self.map_err(|e| Coded::new(code, e))
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Contextualized;
+use std::error::Error;
+use std::process::ExitCode;
+
+/// Maps an error to the exit code of the process.
+/// Defaults to `1`, override `exit_code` for custom codes.
+///
+/// # Examples
+///
+/// ```
+/// use std::process::ExitCode;
+/// use error_traits::IntoExitCode;
+///
+/// #[derive(Debug)]
+/// enum CliError {
+///     Usage,
+///     Io(std::io::Error),
+/// }
+///
+/// impl IntoExitCode for CliError {
+///     fn exit_code(&self) -> u8 {
+///         match self {
+///             CliError::Usage => 2,
+///             CliError::Io(_) => 74,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(CliError::Usage.to_exit_code(), ExitCode::from(2));
+/// ```
+pub trait IntoExitCode {
+    fn exit_code(&self) -> u8 {
+        1
+    }
+
+    fn to_exit_code(&self) -> ExitCode {
+        ExitCode::from(self.exit_code())
+    }
+}
+
+impl IntoExitCode for std::io::Error {}
+
+impl IntoExitCode for String {}
+
+impl IntoExitCode for &str {}
+
+impl IntoExitCode for Box<dyn Error> {}
+
+impl IntoExitCode for Box<dyn Error + Send + Sync> {}
+
+impl<E> IntoExitCode for Contextualized<E>
+where
+    E: IntoExitCode,
+{
+    fn exit_code(&self) -> u8 {
+        self.error().exit_code()
+    }
+}
+
+/// Runs `f`, logging its error (if any) with `LogErr` as `fatal: {err}`
+/// and turning it into the exit code.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::process::ExitCode;
+///
+/// fn main() -> ExitCode {
+///     error_traits::run_main(|| {
+///         let config = std::fs::read_to_string("config.toml")?;
+///         println!("{config}");
+///         Ok::<_, std::io::Error>(())
+///     })
+/// }
+/// ```
+#[cfg(feature = "log_err")]
+#[cfg_attr(feature = "log_location", track_caller)]
+pub fn run_main<E>(f: impl FnOnce() -> crate::StdResult<(), E>) -> ExitCode
+where
+    E: std::fmt::Display + IntoExitCode,
+{
+    use crate::LogErr;
+
+    match f().log_err("fatal: ") {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => e.to_exit_code(),
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{IntoExitCode, StdResult};
+use std::error::Error;
+use std::fmt::{Debug, Display};
+
+/// Conversion of usual results to `eyre::Result`, mirroring `IntoReportDyn`.
+/// Combinators of this crate (`LogErr`, `PassErrWith`, etc.) work on
+/// `eyre::Result` as is.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{IntoEyre, PassErrWith};
+///
+/// let res: eyre::Result<u16> = "foo".parse::<u16>().into_eyre();
+/// let res: eyre::Result<u16> = "foo"
+///     .parse::<u16>()
+///     .wrap_eyre("parsing port")
+///     .pass_err_with(|e| println!("[:: LOG ::] {e:#}"));
+/// assert_eq!(res.unwrap_err().to_string(), "parsing port");
+/// ```
+pub trait IntoEyre<T> {
+    fn into_eyre(self) -> eyre::Result<T>;
+
+    fn wrap_eyre<M>(self, msg: M) -> eyre::Result<T>
+    where
+        M: Display + Debug + Send + Sync + 'static;
+}
+
+impl<T, E> IntoEyre<T> for StdResult<T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn into_eyre(self) -> eyre::Result<T> {
+        self.map_err(eyre::Report::new)
+    }
+
+    fn wrap_eyre<M>(self, msg: M) -> eyre::Result<T>
+    where
+        M: Display + Debug + Send + Sync + 'static,
+    {
+        self.map_err(|e| eyre::Report::new(e).wrap_err(msg))
+    }
+}
+
+impl IntoExitCode for eyre::Report {}
@@ -0,0 +1,82 @@
+use crate::{LogErr, MultiError, StdResult};
+use std::fmt::Display;
+
+/// Result of trying several alternatives in turn, see `OrTry::or_try`.
+/// Holds either the first success, or errors of all failed attempts.
+#[derive(Debug)]
+#[must_use = "call `into_result` to get the value or all the errors"]
+pub struct Fallbacks<T, E> {
+    result: StdResult<T, MultiError<E>>,
+}
+
+impl<T, E> Fallbacks<T, E>
+where
+    E: Display,
+{
+    /// Calls `f` unless one of the previous attempts succeeded.
+    /// Its error is logged at `Level::Warn` and kept.
+    #[cfg_attr(feature = "log_location", track_caller)]
+    pub fn or_try(self, f: impl FnOnce() -> StdResult<T, E>) -> Self {
+        match self.result {
+            Ok(t) => Self { result: Ok(t) },
+            Err(mut errors) => Self {
+                result: attempt(f(), errors.len()).map_err(|e| {
+                    errors.push(e);
+                    errors
+                }),
+            },
+        }
+    }
+
+    /// The first success, or errors of all attempts in order they were made.
+    pub fn into_result(self) -> StdResult<T, MultiError<E>> {
+        self.result
+    }
+}
+
+#[cfg_attr(feature = "log_location", track_caller)]
+fn attempt<T, E>(res: StdResult<T, E>, number: usize) -> StdResult<T, E>
+where
+    E: Display,
+{
+    res.log_warn_err_with(|| format!("attempt {} failed: ", number + 1))
+}
+
+/// Chains fallible alternatives, like `primary().or_try(secondary).or_try(cache)`,
+/// logging every failed attempt via `LogErr` and collecting all errors
+/// if nothing succeeds.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::OrTry;
+///
+/// let port = std::env::var("MISSING_PORT")
+///     .or_try(|| std::env::var("MISSING_FALLBACK_PORT"))
+///     .or_try(|| Ok("8080".to_owned()))
+///     .into_result();
+/// assert_eq!(port.unwrap(), "8080");
+///
+/// let port = "foo"
+///     .parse::<u16>()
+///     .or_try(|| "bar".parse::<u16>())
+///     .into_result();
+/// assert_eq!(port.unwrap_err().len(), 2);
+/// ```
+pub trait OrTry<T, E> {
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn or_try(self, f: impl FnOnce() -> StdResult<T, E>) -> Fallbacks<T, E>;
+}
+
+impl<T, E> OrTry<T, E> for StdResult<T, E>
+where
+    E: Display,
+{
+    fn or_try(self, f: impl FnOnce() -> StdResult<T, E>) -> Fallbacks<T, E> {
+        let first = Fallbacks {
+            result: attempt(self, 0).map_err(|e| MultiError::from(vec![e])),
+        };
+        first.or_try(f)
+    }
+}
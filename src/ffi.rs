@@ -0,0 +1,130 @@
+use crate::{catch_panic, StdResult};
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CString};
+use std::fmt::Display;
+use std::panic::UnwindSafe;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Returned by `catch_ffi` if the closure panicked.
+pub const FFI_PANIC: c_int = -1;
+
+/// Code of `io::Error` that has no raw OS error (or has `0` as one).
+pub const FFI_IO_ERROR: c_int = -2;
+
+/// Maps an error to a C status code. Codes must be non-zero,
+/// since `catch_ffi` returns `0` on success.
+///
+/// Codes used by the crate itself, which custom ones shouldn't overlap with:
+/// - `0`: success;
+/// - `FFI_PANIC` (`-1`): the closure panicked;
+/// - `FFI_IO_ERROR` (`-2`): `io::Error` without a raw OS error;
+/// - positive: raw OS error of `io::Error` (`errno` on Unix).
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::c_int;
+/// use error_traits::{catch_ffi, last_ffi_error, FfiError};
+///
+/// #[derive(Debug)]
+/// enum LibError {
+///     InvalidInput,
+/// }
+///
+/// impl std::fmt::Display for LibError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str("invalid input")
+///     }
+/// }
+///
+/// impl FfiError for LibError {
+///     fn to_c_int(&self) -> c_int {
+///         2
+///     }
+/// }
+///
+/// extern "C" fn lib_process(input: c_int) -> c_int {
+///     catch_ffi(|| match input {
+///         0.. => Ok(()),
+///         _ => Err(LibError::InvalidInput),
+///     })
+/// }
+///
+/// assert_eq!(lib_process(1), 0);
+/// assert_eq!(lib_process(-1), 2);
+/// assert_eq!(last_ffi_error().as_deref(), Some("invalid input"));
+/// ```
+pub trait FfiError {
+    fn to_c_int(&self) -> c_int;
+}
+
+impl<E> FfiError for &E
+where
+    E: FfiError + ?Sized,
+{
+    fn to_c_int(&self) -> c_int {
+        (**self).to_c_int()
+    }
+}
+
+impl FfiError for std::io::Error {
+    /// Raw OS error, if there is one, `FFI_IO_ERROR` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use error_traits::{FfiError, FFI_IO_ERROR};
+    ///
+    /// let error = io::Error::new(io::ErrorKind::Other, "custom");
+    /// assert_eq!(error.to_c_int(), FFI_IO_ERROR);
+    /// ```
+    fn to_c_int(&self) -> c_int {
+        match self.raw_os_error() {
+            Some(0) | None => FFI_IO_ERROR,
+            Some(code) => code,
+        }
+    }
+}
+
+/// Runs `f` at a C ABI boundary: returns `0` on success, `FfiError::to_c_int` on error
+/// and `FFI_PANIC` if `f` panicked. Message of the error (or the panic) is stored
+/// in a thread-local, see `last_ffi_error`.
+pub fn catch_ffi<F, E>(f: F) -> c_int
+where
+    F: FnOnce() -> StdResult<(), E> + UnwindSafe,
+    E: FfiError + Display,
+{
+    let (code, msg) = match catch_panic(f) {
+        Ok(Ok(())) => return 0,
+        Ok(Err(e)) => (e.to_c_int(), e.to_string()),
+        Err(panic) => (FFI_PANIC, panic.to_string()),
+    };
+    let msg = CString::new(msg.replace('\0', "")).expect("NUL bytes are removed");
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(msg));
+    code
+}
+
+/// Message of the last error caught by `catch_ffi` on this thread.
+pub fn last_ffi_error() -> Option<String> {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map(|msg| msg.to_string_lossy().into_owned())
+    })
+}
+
+/// C-callable counterpart of `last_ffi_error`, returning a NUL-terminated
+/// string, or null if there was no error. The pointer stays valid until the
+/// next error is caught on this thread. Re-export it from your library under
+/// a `#[no_mangle]` name to make it visible to C.
+pub extern "C" fn last_ffi_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |msg| msg.as_ptr())
+    })
+}
@@ -0,0 +1,172 @@
+use crate::ChainExt;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+type Scrubber = Box<dyn Fn(&str) -> String + Send + Sync>;
+
+static SCRUBBER: OnceLock<Scrubber> = OnceLock::new();
+
+/// Stable hash of an error, meant for grouping similar errors
+/// (in dashboards, or for deduplication like `LogErrEvery` does).
+/// Hashes length of the error chain, and messages of the error and its sources,
+/// normalized by the scrubber (see `set_fingerprint_scrubber`,
+/// `scrub_volatile` is used by default).
+/// Type names are not hashed, since they aren't guaranteed to be stable.
+///
+/// The hash is the same across runs and platforms, but may change
+/// if messages change.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{Fingerprint, WithContext};
+///
+/// let read = |path: &str| std::fs::read_to_string(path).context(format!("reading {path}"));
+/// let a = read("missing/a.toml").unwrap_err();
+/// let b = read("missing/b.toml").unwrap_err();
+/// assert_eq!(a.fingerprint(), b.fingerprint());
+///
+/// let c = "foo".parse::<u16>().context("reading missing/c.toml").unwrap_err();
+/// assert_ne!(a.fingerprint(), c.fingerprint());
+/// ```
+pub trait Fingerprint {
+    fn fingerprint(&self) -> u64;
+}
+
+impl<E> Fingerprint for E
+where
+    E: ChainExt + ?Sized,
+{
+    fn fingerprint(&self) -> u64 {
+        let mut hash = Fnv1a::new().write(&self.chain().count().to_string());
+        for e in self.chain() {
+            hash = hash.write("\n").write(&scrub(&e.to_string()));
+        }
+        hash.finish()
+    }
+}
+
+/// Same as `Fingerprint::fingerprint`, but only hashes a message,
+/// normalized by the scrubber. For errors that are only `Display`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::fingerprint_message;
+///
+/// assert_eq!(
+///     fingerprint_message("timed out after 3s"),
+///     fingerprint_message("timed out after 10s"),
+/// );
+/// ```
+pub fn fingerprint_message(msg: &str) -> u64 {
+    Fnv1a::new().write(&scrub(msg)).finish()
+}
+
+/// Installs global scrubber, used for normalizing messages before
+/// they are hashed by `Fingerprint` and `fingerprint_message`.
+/// Can only be called once, subsequent calls fail.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{fingerprint_message, scrub_volatile, set_fingerprint_scrubber};
+///
+/// // Also ignore request ids, like "req-5f2a".
+/// set_fingerprint_scrubber(|msg: &str| {
+///     let msg = scrub_volatile(msg);
+///     msg.split(' ')
+///         .map(|word| if word.starts_with("req-") { "<id>" } else { word })
+///         .collect::<Vec<_>>()
+///         .join(" ")
+/// })
+/// .unwrap();
+///
+/// assert_eq!(fingerprint_message("req-5f2a failed"), fingerprint_message("req-a9 failed"));
+/// assert!(set_fingerprint_scrubber(|msg: &str| msg.to_owned()).is_err());
+/// ```
+pub fn set_fingerprint_scrubber(
+    scrubber: impl Fn(&str) -> String + Send + Sync + 'static,
+) -> Result<(), SetScrubberError> {
+    SCRUBBER
+        .set(Box::new(scrubber))
+        .map_err(|_| SetScrubberError)
+}
+
+/// Default scrubber for fingerprints: replaces words that look like paths
+/// (contain `/` or `\`) with `<path>`, runs of digits with `<n>`,
+/// and collapses whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::scrub_volatile;
+///
+/// assert_eq!(
+///     scrub_volatile("can't open /tmp/42.log:  retried 3 times"),
+///     "can't open <path> retried <n> times",
+/// );
+/// ```
+pub fn scrub_volatile(msg: &str) -> String {
+    let mut scrubbed = String::with_capacity(msg.len());
+    for (i, word) in msg.split_whitespace().enumerate() {
+        if i > 0 {
+            scrubbed.push(' ');
+        }
+        if word.contains(['/', '\\']) {
+            scrubbed.push_str("<path>");
+            continue;
+        }
+        let mut in_number = false;
+        for c in word.chars() {
+            match (c.is_ascii_digit(), in_number) {
+                (true, true) => (),
+                (true, false) => scrubbed.push_str("<n>"),
+                (false, _) => scrubbed.push(c),
+            }
+            in_number = c.is_ascii_digit();
+        }
+    }
+    scrubbed
+}
+
+fn scrub(msg: &str) -> String {
+    match SCRUBBER.get() {
+        Some(scrubber) => scrubber(msg),
+        None => scrub_volatile(msg),
+    }
+}
+
+/// 64-bit FNV-1a, unlike `DefaultHasher` it's guaranteed to not change
+/// between Rust releases.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(self, s: &str) -> Self {
+        let hash = s.bytes().fold(self.0, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        Self(hash)
+    }
+
+    fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+/// Returned by `set_fingerprint_scrubber` if global scrubber is already installed.
+#[derive(Debug)]
+pub struct SetScrubberError;
+
+impl Display for SetScrubberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("fingerprint scrubber is already installed")
+    }
+}
+
+impl Error for SetScrubberError {}
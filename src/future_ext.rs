@@ -0,0 +1,186 @@
+use crate::{MapErrBy, MapErrToString, PassErrWith, StdResult};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Same helpers as for `Result`, but applied to the output of a future.
+/// Each method returns a small adapter future, so they can be chained
+/// before `.await`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::FutureResultExt;
+///
+/// let fut = async { "foo".parse::<u16>() }
+///     .pass_err_with(|e| println!("[:: LOG ::] {e}"))
+///     .map_err_to_str();
+/// let result: Result<u16, String> = futures::executor::block_on(fut);
+///
+/// let path = String::from("config.toml");
+/// let fut = async { "foo".parse::<u16>() }.map_err_by(move || path);
+/// assert_eq!(futures::executor::block_on(fut), Err("config.toml".to_owned()));
+/// ```
+pub trait FutureResultExt
+where
+    Self: Future<Output = StdResult<Self::Ok, Self::Error>> + Sized,
+{
+    type Ok;
+    type Error;
+
+    fn pass_err_with<F>(self, f: F) -> PassErrWithFuture<Self, F>
+    where
+        F: FnOnce(&Self::Error),
+    {
+        PassErrWithFuture {
+            fut: self,
+            f: Some(f),
+        }
+    }
+
+    fn map_err_by<N, F>(self, f: F) -> MapErrByFuture<Self, F>
+    where
+        F: FnOnce() -> N,
+    {
+        MapErrByFuture {
+            fut: self,
+            f: Some(f),
+        }
+    }
+
+    fn map_err_to_str(self) -> MapErrToStringFuture<Self>
+    where
+        Self::Error: ToString,
+    {
+        MapErrToStringFuture { fut: self }
+    }
+
+    /// Logs an error (if any) once the future completes.
+    /// With `log_location` feature, location is the one of this call.
+    /// Requires an initialized logger.
+    #[cfg(feature = "log_err")]
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err(self, log_prefix: &str) -> LogErrFuture<'_, Self>
+    where
+        Self::Error: std::fmt::Display,
+    {
+        LogErrFuture {
+            fut: self,
+            log_prefix,
+            location: std::panic::Location::caller(),
+        }
+    }
+}
+
+impl<Fut, T, E> FutureResultExt for Fut
+where
+    Fut: Future<Output = StdResult<T, E>>,
+{
+    type Ok = T;
+    type Error = E;
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the `FutureResultExt::pass_err_with` method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct PassErrWithFuture<Fut, F> {
+        #[pin]
+        fut: Fut,
+        f: Option<F>,
+    }
+}
+
+impl<Fut, T, E, F> Future for PassErrWithFuture<Fut, F>
+where
+    Fut: Future<Output = StdResult<T, E>>,
+    F: FnOnce(&E),
+{
+    type Output = StdResult<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        this.fut
+            .poll(cx)
+            .map(|res| res.pass_err_with(take_fn(this.f)))
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the `FutureResultExt::map_err_by` method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct MapErrByFuture<Fut, F> {
+        #[pin]
+        fut: Fut,
+        f: Option<F>,
+    }
+}
+
+impl<Fut, T, E, N, F> Future for MapErrByFuture<Fut, F>
+where
+    Fut: Future<Output = StdResult<T, E>>,
+    F: FnOnce() -> N,
+{
+    type Output = StdResult<T, N>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        this.fut.poll(cx).map(|res| res.map_err_by(take_fn(this.f)))
+    }
+}
+
+fn take_fn<F>(f: &mut Option<F>) -> F {
+    f.take().expect("future polled after completion")
+}
+
+pin_project_lite::pin_project! {
+    /// Future for the `FutureResultExt::map_err_to_str` method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct MapErrToStringFuture<Fut> {
+        #[pin]
+        fut: Fut,
+    }
+}
+
+impl<Fut, T, E> Future for MapErrToStringFuture<Fut>
+where
+    Fut: Future<Output = StdResult<T, E>>,
+    E: ToString,
+{
+    type Output = StdResult<T, String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().fut.poll(cx).map(|res| res.map_err_to_str())
+    }
+}
+
+#[cfg(feature = "log_err")]
+pin_project_lite::pin_project! {
+    /// Future for the `FutureResultExt::log_err` method.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct LogErrFuture<'a, Fut> {
+        #[pin]
+        fut: Fut,
+        log_prefix: &'a str,
+        location: &'static std::panic::Location<'static>,
+    }
+}
+
+#[cfg(feature = "log_err")]
+impl<Fut, T, E> Future for LogErrFuture<'_, Fut>
+where
+    Fut: Future<Output = StdResult<T, E>>,
+    E: std::fmt::Display,
+{
+    type Output = StdResult<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        use crate::log_err::{log_err_from, TARGET};
+
+        let this = self.project();
+        this.fut.poll(cx).map(|res| {
+            log_err_from(res, this.location, TARGET, log::Level::Error, || {
+                this.log_prefix
+            })
+        })
+    }
+}
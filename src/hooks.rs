@@ -0,0 +1,75 @@
+use crate::StdResult;
+use std::error::Error;
+use std::panic::Location;
+use std::sync::RwLock;
+
+/// Signature of hooks installed with `install_error_hook`.
+pub type ErrorHook = fn(&dyn Error, &HookContext);
+
+static HOOKS: RwLock<Vec<ErrorHook>> = RwLock::new(Vec::new());
+
+/// Information about the place an error was reported from, passed to every hook.
+#[derive(Debug, Clone, Copy)]
+pub struct HookContext {
+    location: &'static Location<'static>,
+}
+
+impl HookContext {
+    /// Location of the `.report()` call.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+/// Registers a hook invoked for every error passed through `ReportErr::report`.
+/// Hooks are called in order of installation.
+pub fn install_error_hook(hook: ErrorHook) {
+    HOOKS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(hook)
+}
+
+/// If error is present, invokes all hooks installed with `install_error_hook`
+/// on it and returns it back. Lets an application decide once what happens
+/// to errors ("send to Sentry", "bump a metric", "log"), while call sites
+/// just write `.report()?`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{install_error_hook, ReportErr};
+///
+/// install_error_hook(|e, ctx| eprintln!("[{}] {e}", ctx.location()));
+///
+/// let number = "foo".parse::<u16>().report();
+/// ```
+pub trait ReportErr {
+    #[track_caller]
+    fn report(self) -> Self;
+}
+
+impl<T, E> ReportErr for StdResult<T, E>
+where
+    E: Error + 'static,
+{
+    #[track_caller]
+    fn report(self) -> Self {
+        if let Err(e) = &self {
+            call_hooks(e, Location::caller())
+        }
+        self
+    }
+}
+
+pub(crate) fn call_hooks(e: &dyn Error, location: &'static Location<'static>) {
+    let ctx = HookContext { location };
+    // Copied out, so that hooks are able to install other hooks.
+    let hooks = HOOKS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+    for hook in hooks {
+        hook(e, &ctx)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{ErrorClass, ErrorKindClass, StdResult};
+pub use http::StatusCode;
+use std::io;
+use std::sync::mpsc::RecvTimeoutError;
+
+/// HTTP status code to respond with when an error reaches a web handler.
+/// Implemented for std errors with sensible defaults, so the mapping
+/// doesn't have to be repeated by every service.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use http::StatusCode;
+/// use error_traits::{HttpStatus, MapErrToStatus};
+///
+/// assert_eq!(io::Error::from(io::ErrorKind::NotFound).status(), StatusCode::NOT_FOUND);
+/// assert_eq!(io::Error::from(io::ErrorKind::TimedOut).status(), StatusCode::GATEWAY_TIMEOUT);
+///
+/// let status = std::fs::read("missing/config.toml").map_err_to_status().unwrap_err();
+/// assert_eq!(status, StatusCode::NOT_FOUND);
+/// ```
+pub trait HttpStatus {
+    fn status(&self) -> StatusCode;
+
+    /// Code to put into `ApiError` response bodies, if error has one.
+    /// Types implementing `ErrorCode` can return `Some(self.code())`.
+    fn error_code(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+impl HttpStatus for ErrorKindClass {
+    fn status(&self) -> StatusCode {
+        match self {
+            ErrorKindClass::Transient => StatusCode::SERVICE_UNAVAILABLE,
+            ErrorKindClass::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            ErrorKindClass::Permanent | ErrorKindClass::Unknown => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl HttpStatus for io::ErrorKind {
+    fn status(&self) -> StatusCode {
+        use io::ErrorKind::*;
+
+        match self {
+            NotFound => StatusCode::NOT_FOUND,
+            PermissionDenied => StatusCode::FORBIDDEN,
+            AlreadyExists => StatusCode::CONFLICT,
+            InvalidInput | InvalidData => StatusCode::BAD_REQUEST,
+            TimedOut => StatusCode::GATEWAY_TIMEOUT,
+            Unsupported => StatusCode::NOT_IMPLEMENTED,
+            ConnectionRefused | ConnectionReset | ConnectionAborted | NotConnected => {
+                StatusCode::BAD_GATEWAY
+            }
+            kind => kind.class().status(),
+        }
+    }
+}
+
+impl HttpStatus for io::Error {
+    fn status(&self) -> StatusCode {
+        self.kind().status()
+    }
+}
+
+impl HttpStatus for RecvTimeoutError {
+    fn status(&self) -> StatusCode {
+        match self {
+            RecvTimeoutError::Timeout => StatusCode::GATEWAY_TIMEOUT,
+            RecvTimeoutError::Disconnected => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+impl<E> HttpStatus for &E
+where
+    E: HttpStatus + ?Sized,
+{
+    fn status(&self) -> StatusCode {
+        (**self).status()
+    }
+
+    fn error_code(&self) -> Option<&'static str> {
+        (**self).error_code()
+    }
+}
+
+/// Replaces error with its `HttpStatus`.
+pub trait MapErrToStatus<T> {
+    fn map_err_to_status(self) -> StdResult<T, StatusCode>;
+}
+
+impl<T, E> MapErrToStatus<T> for StdResult<T, E>
+where
+    E: HttpStatus,
+{
+    fn map_err_to_status(self) -> StdResult<T, StatusCode> {
+        self.map_err(|e| e.status())
+    }
+}
+
+impl<E> HttpStatus for crate::Coded<E>
+where
+    E: HttpStatus,
+{
+    fn status(&self) -> StatusCode {
+        self.error().status()
+    }
+
+    fn error_code(&self) -> Option<&'static str> {
+        Some(crate::ErrorCode::code(self))
+    }
+}
+
+impl<E> HttpStatus for crate::Contextualized<E>
+where
+    E: HttpStatus,
+{
+    fn status(&self) -> StatusCode {
+        self.error().status()
+    }
+
+    fn error_code(&self) -> Option<&'static str> {
+        self.error().error_code()
+    }
+}
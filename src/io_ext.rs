@@ -0,0 +1,69 @@
+use crate::StdResult;
+use std::io;
+
+/// Fluent matching on `io::ErrorKind`, instead of `match` blocks in every
+/// piece of file and network code.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, ErrorKind};
+/// use error_traits::IoErrExt;
+///
+/// let config = std::fs::read_to_string("missing/config.toml")
+///     .if_kind(ErrorKind::NotFound, |_| String::new())
+///     .unwrap();
+/// assert_eq!(config, "");
+///
+/// assert!(Err::<(), _>(io::Error::from(ErrorKind::TimedOut)).is_timeout());
+///
+/// let mut attempts = 0;
+/// let mut read = || {
+///     attempts += 1;
+///     match attempts {
+///         1 => Err(io::Error::from(ErrorKind::Interrupted)),
+///         _ => Ok(attempts),
+///     }
+/// };
+/// assert_eq!(read().retry_interrupted(read).unwrap(), 2);
+/// ```
+pub trait IoErrExt<T>
+where
+    Self: Sized,
+{
+    /// Turns error of the given `kind` into `Ok` with a value built by `f`.
+    /// Other errors are passed through.
+    fn if_kind(self, kind: io::ErrorKind, f: impl FnOnce(io::Error) -> T) -> Self;
+
+    /// Whether it's an error of `TimedOut` or `WouldBlock` kind. Sockets with
+    /// read/write timeouts report the latter on some platforms.
+    fn is_timeout(&self) -> bool;
+
+    /// While it's an `Interrupted` error, replaces it with the result of `op`.
+    /// That's how syscalls interrupted by signals are meant to be handled.
+    fn retry_interrupted(self, op: impl FnMut() -> Self) -> Self;
+}
+
+impl<T> IoErrExt<T> for StdResult<T, io::Error> {
+    fn if_kind(self, kind: io::ErrorKind, f: impl FnOnce(io::Error) -> T) -> Self {
+        match self {
+            Err(e) if e.kind() == kind => Ok(f(e)),
+            res => res,
+        }
+    }
+
+    fn is_timeout(&self) -> bool {
+        matches!(
+            self,
+            Err(e) if matches!(e.kind(), io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock)
+        )
+    }
+
+    fn retry_interrupted(self, mut op: impl FnMut() -> Self) -> Self {
+        let mut res = self;
+        while matches!(&res, Err(e) if e.kind() == io::ErrorKind::Interrupted) {
+            res = op()
+        }
+        res
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{MultiError, StdResult};
+
+/// Helpers for iterators over `Result`s.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::IterResultExt;
+///
+/// let (numbers, errors) = ["1", "foo", "3"].iter().map(|s| s.parse::<u16>()).partition_results();
+/// assert_eq!(numbers, vec![1, 3]);
+/// assert_eq!(errors.len(), 1);
+///
+/// let all: Result<Vec<u16>, _> = ["1", "foo", "bar"].iter().map(|s| s.parse::<u16>()).try_collect_all();
+/// assert_eq!(all.unwrap_err().len(), 2);
+/// ```
+pub trait IterResultExt<T, E>
+where
+    Self: Iterator<Item = StdResult<T, E>> + Sized,
+{
+    /// Splits successes and errors in a single pass.
+    fn partition_results(self) -> (Vec<T>, Vec<E>) {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for res in self {
+            match res {
+                Ok(ok) => oks.push(ok),
+                Err(err) => errs.push(err),
+            }
+        }
+        (oks, errs)
+    }
+
+    /// Unlike `collect::<Result<_, _>>()`, doesn't stop at the first error,
+    /// but returns every one of them.
+    fn try_collect_all<C>(self) -> StdResult<C, MultiError<E>>
+    where
+        C: FromIterator<T>,
+    {
+        let mut errors = MultiError::new();
+        let collected = self
+            .filter_map(|res| res.map_err(|e| errors.push(e)).ok())
+            .collect();
+        errors.into_result().map(|()| collected)
+    }
+
+    /// Collects successes, logging every error using `LogErr`.
+    /// Requires an initialized logger.
+    #[cfg(feature = "log_err")]
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn collect_oks_log_errs(self, log_prefix: &str) -> Vec<T>
+    where
+        E: std::fmt::Display,
+    {
+        use crate::log_err::{log_err_from, TARGET};
+
+        let location = std::panic::Location::caller();
+        self.filter_map(|res| {
+            log_err_from(res, location, TARGET, log::Level::Error, || log_prefix).ok()
+        })
+        .collect()
+    }
+}
+
+impl<I, T, E> IterResultExt<T, E> for I where I: Iterator<Item = StdResult<T, E>> {}
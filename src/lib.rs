@@ -1,12 +1,291 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "anyhow")]
+mod anyhow_ext;
+#[cfg(feature = "axum")]
+mod axum_ext;
+#[cfg(feature = "backtrace")]
+mod backtrace;
+#[cfg(feature = "std")]
+mod catch_panic;
+#[cfg(feature = "std")]
+mod chain;
+#[cfg(feature = "std")]
+mod channel;
+#[cfg(feature = "circuit_breaker")]
+mod circuit_breaker;
+#[cfg(feature = "color")]
+mod color;
+#[cfg(feature = "std")]
+mod context;
+mod control_flow;
+#[cfg(feature = "std")]
+mod correlation;
+#[cfg(feature = "defmt")]
+mod defmt_err;
+#[cfg(feature = "either")]
+mod either_ext;
+#[cfg(feature = "std")]
+mod err_boxed;
+#[cfg(feature = "log_err")]
+mod err_guard;
+#[cfg(feature = "std")]
+mod err_scope;
+#[cfg(feature = "log_err")]
+mod err_sink;
 #[cfg(feature = "error_stack_dyn_ext")]
 mod err_stack_ext;
+#[cfg(all(feature = "errno", unix))]
+mod errno;
+#[cfg(feature = "std")]
+mod error_class;
+#[cfg(feature = "std")]
+mod error_code;
+#[cfg(feature = "std")]
+mod exit_code;
+#[cfg(feature = "eyre")]
+mod eyre_ext;
+#[cfg(feature = "log_err")]
+mod fallback;
+#[cfg(feature = "std")]
+mod ffi;
+#[cfg(feature = "std")]
+mod fingerprint;
+#[cfg(feature = "future_ext")]
+mod future_ext;
+#[cfg(feature = "std")]
+mod hooks;
+#[cfg(feature = "http")]
+mod http_status;
+#[cfg(feature = "std")]
+mod io_ext;
+#[cfg(feature = "std")]
+mod iter_ext;
+#[cfg(feature = "std")]
+mod location;
+#[cfg(feature = "std")]
+mod lock_ext;
 #[cfg(feature = "log_err")]
 mod log_err;
+#[cfg(feature = "log_err_kv")]
+mod log_err_kv;
+#[cfg(feature = "log_err")]
+mod log_format;
+#[cfg(feature = "macros")]
+mod macros;
+#[cfg(feature = "std")]
+mod main_report;
+#[cfg(feature = "metrics")]
+mod metrics_err;
+#[cfg(feature = "miette")]
+mod miette_ext;
+#[cfg(feature = "std")]
+mod multi_error;
+mod option_ext;
+#[cfg(feature = "std")]
+mod pipeline;
+#[cfg(feature = "rayon")]
+mod rayon_ext;
+#[cfg(feature = "std")]
+mod redact;
+mod result_like;
+#[cfg(feature = "retry")]
+mod retry;
+#[cfg(feature = "retry_async")]
+mod retry_async;
+#[cfg(feature = "std")]
+mod scoped_handler;
+#[cfg(feature = "sentry")]
+mod sentry_err;
+#[cfg(feature = "serde")]
+mod serializable;
+mod severity;
+#[cfg(feature = "spantrace")]
+mod spantrace;
+#[cfg(feature = "stream_ext")]
+mod stream_ext;
+#[cfg(feature = "test_utils")]
+mod test_error;
+#[cfg(feature = "test_utils")]
+mod test_utils;
+#[cfg(feature = "std")]
+mod timed;
+#[cfg(feature = "tokio")]
+mod tokio_ext;
+#[cfg(feature = "tonic")]
+mod tonic_ext;
+#[cfg(feature = "tracing_err")]
+mod tracing_err;
+#[cfg(feature = "std")]
+mod truncate;
+#[cfg(feature = "std")]
+mod tuple_results;
+#[cfg(feature = "std")]
+mod validated;
+#[cfg(all(feature = "wasm_console", target_arch = "wasm32"))]
+mod wasm_console;
+#[cfg(feature = "std")]
+mod with_meta;
+
+/**
+Re-exports every trait of the crate, including the ones enabled by features,
+so a single `use error_traits::prelude::*;` brings all extension methods into scope.
+
+# Examples
+
+Basic usage:
+
+```
+use error_traits::prelude::*;
+
+let mut seen = None;
+let num = "x"
+    .parse::<u8>()
+    .pass_err_with(|e| seen = Some(e.to_string()))
+    .map_err_by(|| 0)
+    .merge_ok_err();
+
+assert_eq!(num, 0);
+assert_eq!(seen.as_deref(), Some("invalid digit found in string"));
+```
+*/
+pub mod prelude;
 
+#[cfg(feature = "anyhow")]
+pub use anyhow_ext::*;
+#[cfg(feature = "axum")]
+pub use axum_ext::*;
+#[cfg(feature = "backtrace")]
+pub use backtrace::*;
+#[cfg(feature = "std")]
+pub use catch_panic::*;
+#[cfg(feature = "std")]
+pub use chain::*;
+#[cfg(feature = "std")]
+pub use channel::*;
+#[cfg(feature = "circuit_breaker")]
+pub use circuit_breaker::*;
+#[cfg(feature = "color")]
+pub use color::*;
+#[cfg(feature = "std")]
+pub use context::*;
+pub use control_flow::*;
+#[cfg(feature = "std")]
+pub use correlation::*;
+#[cfg(feature = "defmt")]
+pub use defmt_err::*;
+#[cfg(feature = "either")]
+pub use either_ext::*;
+#[cfg(feature = "std")]
+pub use err_boxed::*;
+#[cfg(feature = "log_err")]
+pub use err_guard::*;
+#[cfg(feature = "std")]
+pub use err_scope::*;
+#[cfg(feature = "log_err")]
+pub use err_sink::*;
 #[cfg(feature = "error_stack_dyn_ext")]
 pub use err_stack_ext::*;
+#[cfg(all(feature = "errno", unix))]
+pub use errno::*;
+#[cfg(feature = "std")]
+pub use error_class::*;
+#[cfg(feature = "std")]
+pub use error_code::*;
+#[cfg(feature = "std")]
+pub use exit_code::*;
+#[cfg(feature = "eyre")]
+pub use eyre_ext::*;
+#[cfg(feature = "log_err")]
+pub use fallback::*;
+#[cfg(feature = "std")]
+pub use ffi::*;
+#[cfg(feature = "std")]
+pub use fingerprint::*;
+#[cfg(feature = "future_ext")]
+pub use future_ext::*;
+#[cfg(feature = "std")]
+pub use hooks::*;
+#[cfg(feature = "http")]
+pub use http_status::*;
+#[cfg(feature = "std")]
+pub use io_ext::*;
+#[cfg(feature = "std")]
+pub use iter_ext::*;
+#[cfg(feature = "std")]
+pub use location::*;
+#[cfg(feature = "std")]
+pub use lock_ext::*;
 #[cfg(feature = "log_err")]
 pub use log_err::*;
+#[cfg(feature = "log_err_kv")]
+pub use log_err_kv::*;
+#[cfg(feature = "log_err")]
+pub use log_format::*;
+#[cfg(feature = "macros")]
+pub use macros::*;
+#[cfg(feature = "std")]
+pub use main_report::*;
+#[cfg(feature = "metrics")]
+pub use metrics_err::*;
+#[cfg(feature = "miette")]
+pub use miette_ext::*;
+#[cfg(feature = "std")]
+pub use multi_error::*;
+pub use option_ext::*;
+#[cfg(feature = "std")]
+pub use pipeline::*;
+#[cfg(feature = "rayon")]
+pub use rayon_ext::*;
+#[cfg(feature = "std")]
+pub use redact::*;
+pub use result_like::*;
+#[cfg(feature = "retry")]
+pub use retry::*;
+#[cfg(feature = "retry_async")]
+pub use retry_async::*;
+#[cfg(feature = "std")]
+pub use scoped_handler::*;
+#[cfg(feature = "sentry")]
+pub use sentry_err::*;
+#[cfg(feature = "serde")]
+pub use serializable::*;
+pub use severity::*;
+#[cfg(feature = "spantrace")]
+pub use spantrace::*;
+#[cfg(feature = "stream_ext")]
+pub use stream_ext::*;
+#[cfg(feature = "test_utils")]
+pub use test_error::*;
+#[cfg(feature = "test_utils")]
+pub use test_utils::*;
+#[cfg(feature = "std")]
+pub use timed::*;
+#[cfg(feature = "tokio")]
+pub use tokio_ext::*;
+#[cfg(feature = "tonic")]
+pub use tonic_ext::*;
+#[cfg(feature = "tracing_err")]
+pub use tracing_err::*;
+#[cfg(feature = "std")]
+pub use truncate::*;
+#[cfg(feature = "std")]
+pub use tuple_results::*;
+#[cfg(feature = "std")]
+pub use validated::*;
+#[cfg(all(feature = "wasm_console", target_arch = "wasm32"))]
+pub use wasm_console::*;
+#[cfg(feature = "std")]
+pub use with_meta::*;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
 
 type StdResult<T, E> = Result<T, E>;
 
@@ -30,9 +309,72 @@ pub trait MergeOkErr<T> {
     fn merge_ok_err(self) -> T;
 }
 
-impl<T> MergeOkErr<T> for StdResult<T, T> {
+impl<R, T> MergeOkErr<T> for R
+where
+    R: ResultLike<Ok = T, Err = T>,
+{
     fn merge_ok_err(self) -> T {
-        self.unwrap_or_else(|err| err)
+        self.into_result().unwrap_or_else(|err| err)
+    }
+}
+
+/**
+Same as `MergeOkErr`, but for `Ok` and `Err` variants of different types,
+which both convert into `U`.
+
+
+# Examples
+
+Basic usage:
+
+```
+use error_traits::MergeInto;
+
+struct Page(String);
+struct ErrorPage(u16);
+
+enum Response {
+    Ok(String),
+    Error(u16),
+}
+
+impl From<Page> for Response {
+    fn from(page: Page) -> Self {
+        Response::Ok(page.0)
+    }
+}
+
+impl From<ErrorPage> for Response {
+    fn from(page: ErrorPage) -> Self {
+        Response::Error(page.0)
+    }
+}
+
+let res: Result<Page, ErrorPage> = Err(ErrorPage(404));
+let response = res.merge_into::<Response>();
+assert!(matches!(response, Response::Error(404)));
+```
+*/
+pub trait MergeInto<T, E> {
+    fn merge_into<U>(self) -> U
+    where
+        T: Into<U>,
+        E: Into<U>;
+}
+
+impl<R> MergeInto<R::Ok, R::Err> for R
+where
+    R: ResultLike,
+{
+    fn merge_into<U>(self) -> U
+    where
+        R::Ok: Into<U>,
+        R::Err: Into<U>,
+    {
+        match self.into_result() {
+            Ok(t) => t.into(),
+            Err(e) => e.into(),
+        }
     }
 }
 
@@ -46,21 +388,121 @@ Required in order to get rid of ugly `.map_err(|_| bar())` calls.
 Basic usage:
 
 ```
-use error_traits::MapErrBy;
+use error_traits::{MapErrBy, TestError};
+
+let error = || TestError::new("not a number");
+let number: Result<u32, TestError> = "42".parse::<u32>().map_err_by(error);
+
+let path = String::from("config.toml");
+let number: Result<u32, String> = "foo".parse::<u32>().map_err_by(move || path);
+
+let number: Result<u32, TestError> = "42".parse::<u32>().map_err_to("not a number".into());
+```
+*/
+pub trait MapErrBy<N>
+where
+    Self: ResultLike,
+{
+    fn map_err_by(self, f: impl FnOnce() -> N) -> Self::WithErr<N>;
+
+    /// Same as `map_err_by`, but takes the new error itself.
+    /// Reads better for cheap errors, like `map_err_to(MyError::Parse)`.
+    fn map_err_to(self, error: N) -> Self::WithErr<N> {
+        self.map_err_by(|| error)
+    }
+}
+
+impl<R, N> MapErrBy<N> for R
+where
+    R: ResultLike,
+{
+    fn map_err_by(self, f: impl FnOnce() -> N) -> R::WithErr<N> {
+        R::from_result_with(self.into_result().map_err(|_| f()))
+    }
+}
+
+/**
+Same as `MapErrBy`, but the closure receives the replaced error by value,
+so it can be moved into the new one without cloning.
+
+
+# Examples
+
+Basic usage:
+
+```
+use error_traits::{MapErrByWith, TestError};
+
+let path = String::from("config.toml");
+let error = "foo"
+    .parse::<u32>()
+    .map_err_by_with(move |cause| TestError::new(path).with_source(cause.to_string()))
+    .unwrap_err();
+assert_eq!(error.message(), "config.toml");
+```
+*/
+pub trait MapErrByWith
+where
+    Self: ResultLike,
+{
+    fn map_err_by_with<N>(self, f: impl FnOnce(Self::Err) -> N) -> Self::WithErr<N>;
+}
+
+impl<R> MapErrByWith for R
+where
+    R: ResultLike,
+{
+    fn map_err_by_with<N>(self, f: impl FnOnce(R::Err) -> N) -> R::WithErr<N> {
+        R::from_result_with(self.into_result().map_err(f))
+    }
+}
+
+/**
+Converts error into another type with its `Into` impl.
+Shorthand for `.map_err(Into::into)`, handy for layered error enums
+that already have `From` impls.
+
+
+# Examples
+
+Basic usage:
+
+```
+use error_traits::ErrInto;
+
+#[derive(Debug)]
+enum AppError {
+    Parse(std::num::ParseIntError),
+}
 
-struct MyError;
+impl From<std::num::ParseIntError> for AppError {
+    fn from(e: std::num::ParseIntError) -> Self {
+        AppError::Parse(e)
+    }
+}
 
-let error = || MyError;
-let number: Result<u32, MyError> = "42".parse::<u32>().map_err_by(error);
+let number = "foo".parse::<u32>().err_into::<AppError>();
+assert!(matches!(number, Err(AppError::Parse(_))));
 ```
 */
-pub trait MapErrBy<T, N> {
-    fn map_err_by(self, f: impl Fn() -> N) -> StdResult<T, N>;
+pub trait ErrInto
+where
+    Self: ResultLike,
+{
+    fn err_into<N>(self) -> Self::WithErr<N>
+    where
+        Self::Err: Into<N>;
 }
 
-impl<T, E, N> MapErrBy<T, N> for StdResult<T, E> {
-    fn map_err_by(self, f: impl Fn() -> N) -> StdResult<T, N> {
-        self.map_err(|_| f())
+impl<R> ErrInto for R
+where
+    R: ResultLike,
+{
+    fn err_into<N>(self) -> R::WithErr<N>
+    where
+        R::Err: Into<N>,
+    {
+        R::from_result_with(self.into_result().map_err(Into::into))
     }
 }
 
@@ -78,16 +520,150 @@ use error_traits::{MapErrBy, MapErrToString};
 let number: Result<u32, String> = "42".parse::<u32>().map_err_to_str();
 ```
 */
-pub trait MapErrToString<T> {
-    fn map_err_to_str(self) -> Result<T, String>;
+#[cfg(feature = "alloc")]
+pub trait MapErrToString
+where
+    Self: ResultLike,
+{
+    fn map_err_to_str(self) -> Self::WithErr<String>;
+}
+
+#[cfg(feature = "alloc")]
+impl<R> MapErrToString for R
+where
+    R: ResultLike,
+    R::Err: ToString,
+{
+    fn map_err_to_str(self) -> R::WithErr<String> {
+        R::from_result_with(self.into_result().map_err(|e| e.to_string()))
+    }
+}
+
+/**
+Turns error into a `Cow<'static, str>`.
+Unlike `map_err_to_str`, static messages are kept as is, without allocating.
+
+
+# Examples
+
+Basic usage:
+
+```
+use std::borrow::Cow;
+use error_traits::MapErrToCow;
+
+let result: Result<u32, &'static str> = Err("not found");
+assert!(matches!(result.map_err_to_cow(), Err(Cow::Borrowed("not found"))));
+```
+*/
+#[cfg(feature = "alloc")]
+pub trait MapErrToCow
+where
+    Self: ResultLike,
+{
+    fn map_err_to_cow(self) -> Self::WithErr<Cow<'static, str>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<R> MapErrToCow for R
+where
+    R: ResultLike,
+    R::Err: Into<Cow<'static, str>>,
+{
+    fn map_err_to_cow(self) -> R::WithErr<Cow<'static, str>> {
+        R::from_result_with(self.into_result().map_err(Into::into))
+    }
+}
+
+/**
+Same as `MapErrToString`, but formats error with `{:?}`,
+so it works for errors that only implement `Debug`.
+Picking between `Display` and `Debug` automatically would require specialization,
+so use `map_err_to_str` when error implements `Display`, and this otherwise
+(or `map_err_to_str_or_dbg` when it implements both).
+
+
+# Examples
+
+Basic usage:
+
+```
+use error_traits::MapErrToDebugString;
+
+#[derive(Debug)]
+struct BindgenError(i32);
+
+let result: Result<u32, BindgenError> = Err(BindgenError(-1));
+assert_eq!(result.map_err_to_dbg_str(), Err("BindgenError(-1)".to_owned()));
+```
+*/
+#[cfg(feature = "alloc")]
+pub trait MapErrToDebugString
+where
+    Self: ResultLike,
+{
+    fn map_err_to_dbg_str(self) -> Self::WithErr<String>;
+}
+
+#[cfg(feature = "alloc")]
+impl<R> MapErrToDebugString for R
+where
+    R: ResultLike,
+    R::Err: core::fmt::Debug,
+{
+    fn map_err_to_dbg_str(self) -> R::WithErr<String> {
+        R::from_result_with(self.into_result().map_err(|e| alloc::format!("{e:?}")))
+    }
+}
+
+/**
+Turns error implementing both `Display` and `Debug` into a string,
+preferring `Display`, and falling back to `{:?}` if `Display` gives an empty message
+(common for generated error types with a stub `Display` impl).
+
+
+# Examples
+
+Basic usage:
+
+```
+use error_traits::MapErrToStrOrDebug;
+
+#[derive(Debug)]
+struct BindgenError(i32);
+
+impl std::fmt::Display for BindgenError {
+    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+let result: Result<u32, BindgenError> = Err(BindgenError(-1));
+assert_eq!(result.map_err_to_str_or_dbg(), Err("BindgenError(-1)".to_owned()));
+
+let result = "foo".parse::<u32>().map_err_to_str_or_dbg();
+assert_eq!(result, Err("invalid digit found in string".to_owned()));
+```
+*/
+#[cfg(feature = "alloc")]
+pub trait MapErrToStrOrDebug
+where
+    Self: ResultLike,
+{
+    fn map_err_to_str_or_dbg(self) -> Self::WithErr<String>;
 }
 
-impl<T, E> MapErrToString<T> for StdResult<T, E>
+#[cfg(feature = "alloc")]
+impl<R> MapErrToStrOrDebug for R
 where
-    E: ToString,
+    R: ResultLike,
+    R::Err: core::fmt::Display + core::fmt::Debug,
 {
-    fn map_err_to_str(self) -> StdResult<T, String> {
-        self.map_err(|e| e.to_string())
+    fn map_err_to_str_or_dbg(self) -> R::WithErr<String> {
+        R::from_result_with(self.into_result().map_err(|e| match e.to_string() {
+            msg if msg.is_empty() => alloc::format!("{e:?}"),
+            msg => msg,
+        }))
     }
 }
 
@@ -106,16 +682,184 @@ let result = "foo".parse::<u16>().pass_err_with(|e| println!("[:: LOG ::] {e}"))
 pub trait PassErrWith {
     type Error;
 
-    fn pass_err_with(self, f: impl Fn(&Self::Error)) -> Self;
+    fn pass_err_with(self, f: impl FnOnce(&Self::Error)) -> Self;
 }
 
-impl<T, E> PassErrWith for Result<T, E> {
-    type Error = E;
+impl<R> PassErrWith for R
+where
+    R: ResultLike,
+{
+    type Error = R::Err;
 
-    fn pass_err_with(self, f: impl Fn(&Self::Error)) -> Self {
-        if let Err(e) = &self {
+    fn pass_err_with(self, f: impl FnOnce(&Self::Error)) -> Self {
+        let res = self.into_result();
+        if let Err(e) = &res {
             f(e)
         }
-        self
+        R::from_result(res)
+    }
+}
+
+/**
+Applies `f` to `Result` type if it is a success and returns it back.
+Counterpart of `PassErrWith` for the `Ok` branch, meant for metrics,
+audit logging, or something alike.
+
+
+# Examples
+
+```
+use error_traits::PassOkWith;
+let result = "42".parse::<u16>().pass_ok_with(|n| println!("[:: AUDIT ::] parsed {n}"));
+```
+*/
+pub trait PassOkWith {
+    type Ok;
+
+    fn pass_ok_with(self, f: impl FnOnce(&Self::Ok)) -> Self;
+}
+
+impl<R> PassOkWith for R
+where
+    R: ResultLike,
+{
+    type Ok = R::Ok;
+
+    fn pass_ok_with(self, f: impl FnOnce(&Self::Ok)) -> Self {
+        let res = self.into_result();
+        if let Ok(t) = &res {
+            f(t)
+        }
+        R::from_result(res)
+    }
+}
+
+/**
+Applies `on_ok` or `on_err`, depending on the variant, and returns `Result` back.
+Combination of `PassOkWith` and `PassErrWith`.
+
+
+# Examples
+
+```
+use error_traits::PassWith;
+let result = "foo".parse::<u16>().pass_with(
+    |n| println!("[:: AUDIT ::] parsed {n}"),
+    |e| println!("[:: LOG ::] {e}"),
+);
+```
+*/
+pub trait PassWith {
+    type Ok;
+    type Error;
+
+    fn pass_with(self, on_ok: impl FnOnce(&Self::Ok), on_err: impl FnOnce(&Self::Error)) -> Self;
+}
+
+impl<R> PassWith for R
+where
+    R: ResultLike,
+{
+    type Ok = R::Ok;
+    type Error = R::Err;
+
+    fn pass_with(self, on_ok: impl FnOnce(&Self::Ok), on_err: impl FnOnce(&Self::Error)) -> Self {
+        let res = self.into_result();
+        match &res {
+            Ok(t) => on_ok(t),
+            Err(e) => on_err(e),
+        }
+        R::from_result(res)
+    }
+}
+
+/**
+Unwraps `Result` that can never be an error, like the ones returned
+by infallible `TryFrom` and `FromStr` impls. Unlike `unwrap()`, it doesn't panic,
+and stops compiling if the error type ever becomes fallible.
+Once `!` is stabilized, `Infallible` becomes an alias for it, so this covers `Result<T, !>` too.
+
+
+# Examples
+
+Basic usage:
+
+```
+use error_traits::UnwrapInfallible;
+
+let number: i64 = i64::try_from(42_i32).unwrap_infallible();
+let string: String = "foo".parse::<String>().unwrap_infallible();
+```
+*/
+pub trait UnwrapInfallible<T> {
+    fn unwrap_infallible(self) -> T;
+}
+
+impl<R> UnwrapInfallible<R::Ok> for R
+where
+    R: ResultLike<Err = core::convert::Infallible>,
+{
+    fn unwrap_infallible(self) -> R::Ok {
+        match self.into_result() {
+            Ok(t) => t,
+            Err(never) => match never {},
+        }
+    }
+}
+
+/**
+Collapses nested `Result`s with different error types into one,
+converting both errors into `E`. Shows up when inner fallible work
+is wrapped by something fallible itself, like a thread or a channel.
+
+
+# Examples
+
+Basic usage:
+
+```
+use error_traits::FlattenErrInto;
+
+#[derive(Debug)]
+enum AppError {
+    Parse(std::num::ParseIntError),
+    Panicked,
+}
+
+impl From<std::num::ParseIntError> for AppError {
+    fn from(e: std::num::ParseIntError) -> Self {
+        AppError::Parse(e)
+    }
+}
+
+impl From<Box<dyn std::any::Any + Send>> for AppError {
+    fn from(_: Box<dyn std::any::Any + Send>) -> Self {
+        AppError::Panicked
+    }
+}
+
+let number = std::thread::spawn(|| "foo".parse::<u16>())
+    .join()
+    .flatten_err_into::<AppError>();
+assert!(matches!(number, Err(AppError::Parse(_))));
+```
+*/
+pub trait FlattenErrInto<T, E1, E2> {
+    fn flatten_err_into<E>(self) -> StdResult<T, E>
+    where
+        E1: Into<E>,
+        E2: Into<E>;
+}
+
+impl<T, E1, E2> FlattenErrInto<T, E1, E2> for StdResult<StdResult<T, E1>, E2> {
+    fn flatten_err_into<E>(self) -> StdResult<T, E>
+    where
+        E1: Into<E>,
+        E2: Into<E>,
+    {
+        match self {
+            Ok(inner) => inner.map_err(Into::into),
+            Err(e) => Err(e.into()),
+        }
     }
 }
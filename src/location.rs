@@ -0,0 +1,98 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::panic::Location;
+
+/// Error together with `file:line:column` of the place it was wrapped at.
+#[derive(Debug)]
+pub struct Located<E> {
+    error: E,
+    location: &'static Location<'static>,
+}
+
+impl<E> Located<E> {
+    /// Wraps `error`, capturing location of the caller.
+    #[track_caller]
+    pub fn new(error: E) -> Self {
+        Self {
+            error,
+            location: Location::caller(),
+        }
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<E> Located<E>
+where
+    E: Error + 'static,
+{
+    /// Same as `ToSerializableError::to_serializable`, but also fills `location`
+    /// with the captured `file:line:column`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use error_traits::ErrHere;
+    ///
+    /// let error = "foo".parse::<u16>().err_here().unwrap_err();
+    /// let report = error.to_serializable_located();
+    /// assert_eq!(report.location, Some(error.location().to_string()));
+    /// assert!(report.location.unwrap().starts_with(file!()));
+    /// ```
+    pub fn to_serializable_located(&self) -> crate::SerializableError {
+        crate::ToSerializableError::to_serializable(self).with_location(self.location.to_string())
+    }
+}
+
+impl<E> Display for Located<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (at {})", self.error, self.location)
+    }
+}
+
+impl<E> Error for Located<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Wraps error (if any) into `Located`, capturing `file:line:column` of the call.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::ErrHere;
+///
+/// let (error, line) = ("foo".parse::<u16>().err_here().unwrap_err(), line!());
+/// assert_eq!(error.location().line(), line);
+/// ```
+pub trait ErrHere<T, E> {
+    #[track_caller]
+    fn err_here(self) -> StdResult<T, Located<E>>;
+}
+
+impl<T, E> ErrHere<T, E> for StdResult<T, E> {
+    #[track_caller]
+    fn err_here(self) -> StdResult<T, Located<E>> {
+        let location = Location::caller();
+        self.map_err(|error| Located { error, location })
+    }
+}
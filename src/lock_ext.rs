@@ -0,0 +1,104 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::PoisonError;
+
+/// Poisoned lock, without the guard, so it can be stored, sent across threads
+/// and converted into other errors. Returned by `LockResultExt::map_poison_to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PoisonedLock;
+
+impl Display for PoisonedLock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("lock poisoned by a panicked thread")
+    }
+}
+
+impl Error for PoisonedLock {}
+
+/// Helpers for `LockResult`, returned by `Mutex::lock`, `RwLock::read` and alike.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Mutex;
+/// use error_traits::{LockResultExt, PoisonedLock};
+///
+/// let counter = Mutex::new(0);
+///
+/// *counter.lock().ignore_poison() += 1;
+///
+/// let value = counter.lock().map_poison_to::<PoisonedLock>().map(|guard| *guard);
+/// assert_eq!(value, Ok(1));
+/// ```
+pub trait LockResultExt<G> {
+    /// Returns the guard, whether the lock is poisoned or not.
+    fn ignore_poison(self) -> G;
+
+    /// Replaces `PoisonError`, which borrows the lock, with an owned error.
+    fn map_poison_to<E>(self) -> StdResult<G, E>
+    where
+        E: From<PoisonedLock>;
+}
+
+impl<G> LockResultExt<G> for StdResult<G, PoisonError<G>> {
+    fn ignore_poison(self) -> G {
+        self.unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn map_poison_to<E>(self) -> StdResult<G, E>
+    where
+        E: From<PoisonedLock>,
+    {
+        self.map_err(|_| PoisonedLock.into())
+    }
+}
+
+/// Locks the mutex, and if it is poisoned, logs `log_prefix` and clears the poison,
+/// so that following locks succeed again.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Mutex;
+/// use error_traits::ClearPoisonLog;
+///
+/// let counter = Mutex::new(0);
+/// let _ = std::panic::catch_unwind(|| {
+///     let _guard = counter.lock().unwrap();
+///     panic!("poisoning the lock");
+/// });
+/// assert!(counter.is_poisoned());
+///
+/// *counter.clear_poison_log("recovered counter: ") += 1;
+/// assert!(!counter.is_poisoned());
+/// assert_eq!(*counter.lock().unwrap(), 1);
+/// ```
+#[cfg(feature = "log_err")]
+pub trait ClearPoisonLog<T>
+where
+    T: ?Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn clear_poison_log(&self, log_prefix: &str) -> std::sync::MutexGuard<'_, T>;
+}
+
+#[cfg(feature = "log_err")]
+impl<T> ClearPoisonLog<T> for std::sync::Mutex<T>
+where
+    T: ?Sized,
+{
+    fn clear_poison_log(&self, log_prefix: &str) -> std::sync::MutexGuard<'_, T> {
+        use crate::LogErr;
+
+        match self.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                let _ = Err::<(), _>(PoisonedLock).log_err(log_prefix);
+                self.clear_poison();
+                poisoned.into_inner()
+            }
+        }
+    }
+}
@@ -1,8 +1,35 @@
-use crate::StdResult;
-use std::fmt::Display;
+use crate::{
+    global_sink, log_format::LogMessage, truncate_msg, ChainExt, ErrorCode, Redact, ResultLike,
+    Severity, SeverityLevel, StdResult,
+};
+use log::Level;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::panic::Location;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Target of messages, unless another one is given explicitly.
+pub(crate) const TARGET: &str = module_path!();
 
 /// If error is present, this trait logs it and returns back.
-/// Requires an initialized logger.
+/// Requires an initialized logger (or another `ErrSink`, see `set_global_sink`).
+///
+/// `log_err` always logs at `Level::Error`, other levels are available
+/// through `log_err_at` and its shorthands.
+/// Every method has a `_with` variant that builds the prefix lazily,
+/// only when there is an error to log.
+/// Records are emitted under this module's target unless one is given
+/// to `log_err_target` or `log_err_target_at_with`.
+/// Error messages go through the global redactor (see `set_redactor`),
+/// or through the one given to `log_err_redacted`.
+/// Messages are `{prefix}{err}`, unless another format is installed
+/// with `set_log_format` or `set_log_template`.
+/// With `log_location` feature, prefix starts with `[file:line:column]`
+/// of the call, and records of `LogSink` carry its file and line (this also
+/// holds for the future, stream, retry, timing, iterator and guard helpers).
+/// Target is `error_traits::log_err` for all of them, unless given explicitly;
+/// only `log_err!` uses the caller's module path.
 ///
 /// # Examples
 ///
@@ -11,20 +38,684 @@ use std::fmt::Display;
 /// use error_traits::LogErr;
 ///
 /// let error = "foo".parse::<SocketAddr>().log_err("some_log_prefix: error");
+/// let warning = "foo".parse::<SocketAddr>().log_warn_err("some_log_prefix: warning");
+/// let info = "foo".parse::<SocketAddr>().log_err_at(log::Level::Info, "some_log_prefix: info");
+///
+/// let request_id = 42;
+/// let lazy = "foo".parse::<SocketAddr>().log_err_with(|| format!("[request {request_id}]: "));
+///
+/// let targeted = "foo".parse::<SocketAddr>().log_err_target("net", "some_log_prefix: error");
+/// let located = "foo".parse::<SocketAddr>().log_err_located("some_log_prefix: error");
+///
+/// let redactor = |msg: &str| msg.replace("foo", "***");
+/// let redacted = "foo".parse::<SocketAddr>().log_err_redacted(&redactor, "some_log_prefix: error");
 /// ```
+pub trait LogErr
+where
+    Self: Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_target_at_with<P>(
+        self,
+        target: &str,
+        level: Level,
+        log_prefix: impl FnOnce() -> P,
+    ) -> Self
+    where
+        P: Display;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_redacted_at(self, level: Level, redactor: &impl Redact, log_prefix: &str) -> Self;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_redacted(self, redactor: &impl Redact, log_prefix: &str) -> Self {
+        self.log_err_redacted_at(Level::Error, redactor, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_target_at(self, target: &str, level: Level, log_prefix: &str) -> Self {
+        self.log_err_target_at_with(target, level, || log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_target(self, target: &str, log_prefix: &str) -> Self {
+        self.log_err_target_at(target, Level::Error, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_at_with<P>(self, level: Level, log_prefix: impl FnOnce() -> P) -> Self
+    where
+        P: Display,
+    {
+        self.log_err_target_at_with(TARGET, level, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_at(self, level: Level, log_prefix: &str) -> Self {
+        self.log_err_at_with(level, || log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err(self, log_prefix: &str) -> Self {
+        self.log_err_at(Level::Error, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_warn_err(self, log_prefix: &str) -> Self {
+        self.log_err_at(Level::Warn, log_prefix)
+    }
 
-pub trait LogErr {
-    fn log_err(self, log_msg: &str) -> Self;
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_info_err(self, log_prefix: &str) -> Self {
+        self.log_err_at(Level::Info, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_debug_err(self, log_prefix: &str) -> Self {
+        self.log_err_at(Level::Debug, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_trace_err(self, log_prefix: &str) -> Self {
+        self.log_err_at(Level::Trace, log_prefix)
+    }
+
+    /// Same as `log_err`, but prepends `file:line:column` of the call to the message.
+    /// With `log_location` feature every method does that, so this is just `log_err`.
+    #[track_caller]
+    fn log_err_located(self, log_prefix: &str) -> Self {
+        #[cfg(feature = "log_location")]
+        return self.log_err(log_prefix);
+        #[cfg(not(feature = "log_location"))]
+        {
+            let location = Location::caller();
+            self.log_err_with(|| format!("[{location}] {log_prefix}"))
+        }
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_with<P>(self, log_prefix: impl FnOnce() -> P) -> Self
+    where
+        P: Display,
+    {
+        self.log_err_at_with(Level::Error, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_warn_err_with<P>(self, log_prefix: impl FnOnce() -> P) -> Self
+    where
+        P: Display,
+    {
+        self.log_err_at_with(Level::Warn, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_info_err_with<P>(self, log_prefix: impl FnOnce() -> P) -> Self
+    where
+        P: Display,
+    {
+        self.log_err_at_with(Level::Info, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_debug_err_with<P>(self, log_prefix: impl FnOnce() -> P) -> Self
+    where
+        P: Display,
+    {
+        self.log_err_at_with(Level::Debug, log_prefix)
+    }
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_trace_err_with<P>(self, log_prefix: impl FnOnce() -> P) -> Self
+    where
+        P: Display,
+    {
+        self.log_err_at_with(Level::Trace, log_prefix)
+    }
 }
 
-impl<T, E> LogErr for StdResult<T, E>
+impl<R> LogErr for R
+where
+    R: ResultLike,
+    R::Err: Display,
+{
+    fn log_err_target_at_with<P>(
+        self,
+        target: &str,
+        level: Level,
+        log_prefix: impl FnOnce() -> P,
+    ) -> Self
+    where
+        P: Display,
+    {
+        let res = self.into_result();
+        R::from_result(log_err_from(
+            res,
+            Location::caller(),
+            target,
+            level,
+            log_prefix,
+        ))
+    }
+
+    fn log_err_redacted_at(self, level: Level, redactor: &impl Redact, log_prefix: &str) -> Self {
+        let res = self.into_result();
+        if let Err(e) = &res {
+            if global_sink().enabled(TARGET, level) {
+                let err = redactor.redact(&e.to_string());
+                emit(Location::caller(), TARGET, level, &log_prefix, &err)
+            }
+        }
+        R::from_result(res)
+    }
+}
+
+/// Same as `LogErr::log_err_target_at_with`, but with the location of the call
+/// given explicitly, for helpers that log later (like future adapters)
+/// or from inside a closure.
+pub(crate) fn log_err_from<T, E, P>(
+    res: StdResult<T, E>,
+    location: &'static Location<'static>,
+    target: &str,
+    level: Level,
+    log_prefix: impl FnOnce() -> P,
+) -> StdResult<T, E>
 where
     E: Display,
+    P: Display,
 {
-    fn log_err(self, log_prefix: &str) -> Self {
+    if let Err(e) = &res {
+        if global_sink().enabled(target, level) {
+            let prefix = log_prefix();
+            match crate::redactor() {
+                Some(redactor) => {
+                    let err = redactor.redact(&e.to_string());
+                    emit(location, target, level, &prefix, &err)
+                }
+                None => emit(location, target, level, &prefix, e),
+            }
+        }
+    }
+    res
+}
+
+/// Emits already redacted error through the global sink, in the global format.
+/// With `log_location` feature, prefix starts with `location`.
+pub(crate) fn emit(
+    location: &'static Location<'static>,
+    target: &str,
+    level: Level,
+    prefix: &dyn Display,
+    err: &dyn Display,
+) {
+    #[cfg(feature = "log_location")]
+    let (prefix, location) = (&format_args!("[{location}] {prefix}"), Some(location));
+    #[cfg(not(feature = "log_location"))]
+    let location = {
+        let _ = location;
+        None
+    };
+    let msg = LogMessage { prefix, err };
+    global_sink().emit_at(location, target, level, format_args!("{msg}"))
+}
+
+/// Same as `LogErr`, but formats error with `{:?}`, so it works for any `E: Debug`.
+/// Formatted error goes through the global redactor as well.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{LogErrDebug, TestError};
+///
+/// let result: Result<u32, TestError> = Err("boom".into());
+/// let result = result.log_err_dbg("some_log_prefix: error");
+/// ```
+pub trait LogErrDebug
+where
+    Self: Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_dbg_at(self, level: Level, log_prefix: &str) -> Self;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_dbg(self, log_prefix: &str) -> Self {
+        self.log_err_dbg_at(Level::Error, log_prefix)
+    }
+}
+
+impl<T, E> LogErrDebug for StdResult<T, E>
+where
+    E: Debug,
+{
+    fn log_err_dbg_at(self, level: Level, log_prefix: &str) -> Self {
         if let Err(e) = &self {
-            log::error!("{log_prefix}{e}")
+            let err = Err::<(), _>(format_args!("{e:?}"));
+            let _ = log_err_from(err, Location::caller(), TARGET, level, || log_prefix);
         }
         self
     }
 }
+
+impl From<SeverityLevel> for Level {
+    fn from(severity: SeverityLevel) -> Self {
+        match severity {
+            SeverityLevel::Fatal | SeverityLevel::Error => Level::Error,
+            SeverityLevel::Warning => Level::Warn,
+            SeverityLevel::Info => Level::Info,
+        }
+    }
+}
+
+/// Same as `LogErr::log_err`, but picks log level from error's `Severity`.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{LogErrBySeverity, Severity, SeverityLevel};
+///
+/// struct Timeout;
+///
+/// impl std::fmt::Display for Timeout {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str("timed out")
+///     }
+/// }
+///
+/// impl Severity for Timeout {
+///     fn severity(&self) -> SeverityLevel {
+///         SeverityLevel::Warning
+///     }
+/// }
+///
+/// let result: Result<(), Timeout> = Err(Timeout);
+/// let result = result.log_err_by_severity("request failed: ");
+/// ```
+pub trait LogErrBySeverity {
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_by_severity(self, log_prefix: &str) -> Self;
+}
+
+impl<T, E> LogErrBySeverity for StdResult<T, E>
+where
+    E: Display + Severity,
+{
+    fn log_err_by_severity(self, log_prefix: &str) -> Self {
+        let level = match &self {
+            Err(e) => e.severity().into(),
+            Ok(_) => return self,
+        };
+        self.log_err_at(level, log_prefix)
+    }
+}
+
+/// Same as `LogErr::log_err`, but identical messages are logged at most once per `window`.
+/// Messages are compared by `fingerprint_message`, so ones differing only in
+/// numbers or paths count as identical. Duplicates are counted, and the next
+/// message that gets through reports how many similar errors were suppressed.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::SocketAddr;
+/// use std::time::Duration;
+/// use error_traits::LogErrEvery;
+///
+/// for _ in 0..100 {
+///     let _ = "foo".parse::<SocketAddr>().log_err_every(Duration::from_secs(10), "retry failed: ");
+/// }
+/// ```
+pub trait LogErrEvery
+where
+    Self: Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_every_at(self, level: Level, window: Duration, log_prefix: &str) -> Self;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_every(self, window: Duration, log_prefix: &str) -> Self {
+        self.log_err_every_at(Level::Error, window, log_prefix)
+    }
+}
+
+impl<T, E> LogErrEvery for StdResult<T, E>
+where
+    E: Display,
+{
+    fn log_err_every_at(self, level: Level, window: Duration, log_prefix: &str) -> Self {
+        if let Err(e) = &self {
+            if global_sink().enabled(TARGET, level) {
+                let err = match crate::redactor() {
+                    Some(redactor) => redactor.redact(&e.to_string()),
+                    None => e.to_string(),
+                };
+                let location = Location::caller();
+                match check_recent_errors(&format!("{log_prefix}{err}"), window) {
+                    Some(0) => emit(location, TARGET, level, &log_prefix, &err),
+                    Some(suppressed) => emit(
+                        location,
+                        TARGET,
+                        level,
+                        &log_prefix,
+                        &format_args!("{err} (suppressed {suppressed} similar errors)"),
+                    ),
+                    None => (),
+                }
+            }
+        }
+        self
+    }
+}
+
+struct RecentError {
+    logged_at: Instant,
+    window: Duration,
+    suppressed: usize,
+}
+
+/// Entries are pruned once there are more of them than this.
+const MAX_RECENT_ERRORS: usize = 1024;
+
+/// Returns `None` if `msg` must be suppressed, or number of duplicates
+/// suppressed since it was logged last time.
+fn check_recent_errors(msg: &str, window: Duration) -> Option<usize> {
+    // Fingerprints of recently logged messages, shared by all `LogErrEvery` calls.
+    static RECENT: OnceLock<Mutex<HashMap<u64, RecentError>>> = OnceLock::new();
+
+    let fingerprint = crate::fingerprint_message(msg);
+
+    let mut recent = RECENT
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let now = Instant::now();
+
+    if let Some(entry) = recent.get_mut(&fingerprint) {
+        if now.duration_since(entry.logged_at) < window {
+            entry.suppressed += 1;
+            return None;
+        }
+        let suppressed = entry.suppressed;
+        *entry = RecentError {
+            logged_at: now,
+            window,
+            suppressed: 0,
+        };
+        return Some(suppressed);
+    }
+
+    if recent.len() >= MAX_RECENT_ERRORS {
+        recent.retain(|_, entry| now.duration_since(entry.logged_at) < entry.window);
+    }
+    recent.insert(
+        fingerprint,
+        RecentError {
+            logged_at: now,
+            window,
+            suppressed: 0,
+        },
+    );
+    Some(0)
+}
+
+/// Same as `LogErr::log_err`, but only logs errors matching a predicate,
+/// so expected ones (like `NotFound` in a lookup loop) don't clutter logs.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::ErrorKind;
+/// use error_traits::LogErrIf;
+///
+/// let config = std::fs::read_to_string("missing/config.toml")
+///     .log_err_unless(|e| e.kind() == ErrorKind::NotFound, "reading config: ");
+/// let config = std::fs::read_to_string("missing/config.toml")
+///     .log_err_if(|e| e.kind() == ErrorKind::PermissionDenied, "reading config: ");
+/// ```
+pub trait LogErrIf<E>
+where
+    Self: Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_if(self, predicate: impl FnOnce(&E) -> bool, log_prefix: &str) -> Self;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_unless(self, predicate: impl FnOnce(&E) -> bool, log_prefix: &str) -> Self {
+        self.log_err_if(|e| !predicate(e), log_prefix)
+    }
+}
+
+impl<T, E> LogErrIf<E> for StdResult<T, E>
+where
+    E: Display,
+{
+    fn log_err_if(self, predicate: impl FnOnce(&E) -> bool, log_prefix: &str) -> Self {
+        match &self {
+            Err(e) if predicate(e) => self.log_err(log_prefix),
+            _ => self,
+        }
+    }
+}
+
+/// Same as `LogErr::log_err`, but logs the whole chain of the error
+/// as a tree, see `ChainExt::pretty_chain`.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{LogErrPretty, WithContext};
+///
+/// let result = "foo".parse::<u16>().context("parsing port").log_err_pretty("startup failed: ");
+/// ```
+pub trait LogErrPretty
+where
+    Self: Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_pretty_at(self, level: Level, log_prefix: &str) -> Self;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_pretty(self, log_prefix: &str) -> Self {
+        self.log_err_pretty_at(Level::Error, log_prefix)
+    }
+}
+
+impl<T, E> LogErrPretty for StdResult<T, E>
+where
+    E: std::error::Error + 'static,
+{
+    fn log_err_pretty_at(self, level: Level, log_prefix: &str) -> Self {
+        if let Err(e) = &self {
+            let sink = global_sink();
+            if sink.enabled(TARGET, level) {
+                emit(
+                    Location::caller(),
+                    TARGET,
+                    level,
+                    &log_prefix,
+                    &e.pretty_chain(),
+                )
+            }
+        }
+        self
+    }
+}
+
+/// Same as `LogErr::log_err`, but error message is capped at `max_len` bytes
+/// with `truncate_msg`. Prefix is never truncated.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::LogErrTruncated;
+///
+/// let result: Result<(), String> = Err("x".repeat(10_000));
+/// let result = result.log_err_truncated(256, "upstream failed: ");
+/// ```
+pub trait LogErrTruncated
+where
+    Self: Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_truncated_at(self, level: Level, max_len: usize, log_prefix: &str) -> Self;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_truncated(self, max_len: usize, log_prefix: &str) -> Self {
+        self.log_err_truncated_at(Level::Error, max_len, log_prefix)
+    }
+}
+
+impl<T, E> LogErrTruncated for StdResult<T, E>
+where
+    E: Display,
+{
+    fn log_err_truncated_at(self, level: Level, max_len: usize, log_prefix: &str) -> Self {
+        if let Err(e) = &self {
+            let sink = global_sink();
+            if sink.enabled(TARGET, level) {
+                let msg = match crate::redactor() {
+                    Some(redactor) => redactor.redact(&e.to_string()),
+                    None => e.to_string(),
+                };
+                let err = truncate_msg(&msg, max_len);
+                emit(Location::caller(), TARGET, level, &log_prefix, &err)
+            }
+        }
+        self
+    }
+}
+
+/// Same as `LogErr::log_err`, but puts error's `ErrorCode` in front of the message,
+/// like `[DB_TIMEOUT] timed out`.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{LogErrCoded, WithCode};
+///
+/// let result = "foo".parse::<u16>().map_err_with_code("E_PORT").log_err_coded("config: ");
+/// ```
+pub trait LogErrCoded
+where
+    Self: Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_coded_at(self, level: Level, log_prefix: &str) -> Self;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_coded(self, log_prefix: &str) -> Self {
+        self.log_err_coded_at(Level::Error, log_prefix)
+    }
+}
+
+impl<T, E> LogErrCoded for StdResult<T, E>
+where
+    E: Display + ErrorCode,
+{
+    fn log_err_coded_at(self, level: Level, log_prefix: &str) -> Self {
+        let code = match &self {
+            Err(e) => e.code(),
+            Ok(_) => return self,
+        };
+        self.log_err_at_with(level, || format!("{log_prefix}[{code}] "))
+    }
+}
+
+/// Same as `unwrap` and `expect`, but the error is logged through `LogErr`
+/// before panicking, so that logs contain the error itself,
+/// not only the location of the panic.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```should_panic
+/// use error_traits::UnwrapOrLog;
+///
+/// let number = "42".parse::<u16>().unwrap_or_log("invalid number: ");
+/// let port = "foo".parse::<u16>().expect_or_log("PORT must be a number");
+/// ```
+pub trait UnwrapOrLog {
+    type Ok;
+
+    /// Logs `log_prefix` followed by the error, then panics with the same message.
+    #[track_caller]
+    fn unwrap_or_log(self, log_prefix: &str) -> Self::Ok;
+
+    /// Logs and panics with `"{msg}: {error}"`, like `expect` does.
+    #[track_caller]
+    fn expect_or_log(self, msg: &str) -> Self::Ok;
+}
+
+impl<T, E> UnwrapOrLog for StdResult<T, E>
+where
+    E: Display,
+{
+    type Ok = T;
+
+    #[track_caller]
+    fn unwrap_or_log(self, log_prefix: &str) -> T {
+        match self.log_err(log_prefix) {
+            Ok(t) => t,
+            Err(e) => panic!("{log_prefix}{e}"),
+        }
+    }
+
+    #[track_caller]
+    fn expect_or_log(self, msg: &str) -> T {
+        match self.log_err_with(|| format!("{msg}: ")) {
+            Ok(t) => t,
+            Err(e) => panic!("{msg}: {e}"),
+        }
+    }
+}
+
+/// Same as `unwrap_or` and `unwrap_or_default`, but the swallowed error
+/// is logged through `LogErr` first, so failures don't go unnoticed.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::UnwrapOrLogged;
+///
+/// let retries = "foo".parse::<u8>().unwrap_or_default_logged("invalid RETRIES: ");
+/// assert_eq!(retries, 0);
+///
+/// let port = "foo".parse::<u16>().unwrap_or_logged(8080, "invalid PORT: ");
+/// assert_eq!(port, 8080);
+/// ```
+pub trait UnwrapOrLogged {
+    type Ok;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn unwrap_or_logged(self, value: Self::Ok, log_prefix: &str) -> Self::Ok;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn unwrap_or_default_logged(self, log_prefix: &str) -> Self::Ok
+    where
+        Self::Ok: Default;
+}
+
+impl<T, E> UnwrapOrLogged for StdResult<T, E>
+where
+    E: Display,
+{
+    type Ok = T;
+
+    fn unwrap_or_logged(self, value: T, log_prefix: &str) -> T {
+        self.log_err(log_prefix).unwrap_or(value)
+    }
+
+    fn unwrap_or_default_logged(self, log_prefix: &str) -> T
+    where
+        T: Default,
+    {
+        self.log_err(log_prefix).unwrap_or_default()
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{global_sink, log_err::TARGET, StdResult};
+use log::kv::{self, Key, Source, ToValue, Value, VisitSource};
+use log::Level;
+use std::error::Error;
+use std::panic::Location;
+
+/// If error is present, this trait logs `log_msg` with the error, its
+/// `source()` chain and given key-values attached as structured fields.
+/// Goes through the global sink (see `ErrSink::emit_kv`), and error messages
+/// through the global redactor (see `set_redactor`).
+/// With `log_location` feature, records carry file and line of the call.
+///
+/// Error goes under the `error` key and its sources (if any) under
+/// `error.sources`, joined with `": "`.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::SocketAddr;
+/// use error_traits::LogErrKv;
+///
+/// let request_id = 42;
+/// let error = "foo"
+///     .parse::<SocketAddr>()
+///     .log_err_kv("failed to parse address", &[("request_id", &request_id), ("user", &"bob")]);
+/// ```
+///
+/// Sinks not overriding `ErrSink::emit_kv` get fields appended to the message:
+///
+/// ```
+/// use std::fmt::Arguments;
+/// use std::sync::Mutex;
+/// use error_traits::{set_global_sink, set_redactor, ErrSink, LogErrKv};
+///
+/// static LINES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// struct Capture;
+///
+/// impl ErrSink for Capture {
+///     fn emit(&self, _target: &str, _level: log::Level, msg: Arguments<'_>) {
+///         LINES.lock().unwrap().push(msg.to_string())
+///     }
+/// }
+///
+/// set_global_sink(Capture).unwrap();
+/// set_redactor(|msg: &str| msg.replace("digit", "*****")).unwrap();
+/// let _ = "foo".parse::<u16>().log_err_kv("failed to parse port", &[("request_id", &42)]);
+/// assert_eq!(
+///     LINES.lock().unwrap()[0],
+///     "failed to parse port error=invalid ***** found in string request_id=42"
+/// );
+/// ```
+pub trait LogErrKv
+where
+    Self: Sized,
+{
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_kv_at(self, level: Level, log_msg: &str, kvs: &[(&str, &dyn ToValue)]) -> Self;
+
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err_kv(self, log_msg: &str, kvs: &[(&str, &dyn ToValue)]) -> Self {
+        self.log_err_kv_at(Level::Error, log_msg, kvs)
+    }
+}
+
+impl<T, E> LogErrKv for StdResult<T, E>
+where
+    E: Error,
+{
+    fn log_err_kv_at(self, level: Level, log_msg: &str, kvs: &[(&str, &dyn ToValue)]) -> Self {
+        if let Err(e) = &self {
+            let sink = global_sink();
+            if sink.enabled(TARGET, level) {
+                let redact = |msg: String| match crate::redactor() {
+                    Some(redactor) => redactor.redact(&msg),
+                    None => msg,
+                };
+                let fields = ErrorFields {
+                    error: redact(e.to_string()),
+                    sources: source_chain(e).map(redact),
+                    kvs,
+                };
+                let location = if cfg!(feature = "log_location") {
+                    Some(Location::caller())
+                } else {
+                    None
+                };
+                sink.emit_kv(location, TARGET, level, format_args!("{log_msg}"), &fields);
+            }
+        }
+        self
+    }
+}
+
+fn source_chain(e: &dyn Error) -> Option<String> {
+    let mut sources = Vec::new();
+    let mut source = e.source();
+    while let Some(s) = source {
+        sources.push(s.to_string());
+        source = s.source();
+    }
+    (!sources.is_empty()).then(|| sources.join(": "))
+}
+
+struct ErrorFields<'a> {
+    error: String,
+    sources: Option<String>,
+    kvs: &'a [(&'a str, &'a dyn ToValue)],
+}
+
+impl Source for ErrorFields<'_> {
+    fn visit<'kvs>(&'kvs self, visitor: &mut dyn VisitSource<'kvs>) -> Result<(), kv::Error> {
+        visitor.visit_pair(Key::from_str("error"), Value::from(self.error.as_str()))?;
+        if let Some(sources) = &self.sources {
+            visitor.visit_pair(
+                Key::from_str("error.sources"),
+                Value::from(sources.as_str()),
+            )?;
+        }
+        for (key, value) in self.kvs {
+            visitor.visit_pair(Key::from_str(key), value.to_value())?;
+        }
+        Ok(())
+    }
+}
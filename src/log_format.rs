@@ -0,0 +1,164 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+type Format =
+    Box<dyn Fn(&mut Formatter<'_>, &dyn Display, &dyn Display) -> std::fmt::Result + Send + Sync>;
+
+static FORMAT: OnceLock<Format> = OnceLock::new();
+
+/// Installs global format of messages emitted by `LogErr` and other logging
+/// traits of `log_err` feature, instead of the default `{prefix}{err}`.
+/// The callback receives the prefix and the (already redacted) error.
+/// Can only be called once (together with `set_log_template`), subsequent calls fail.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::set_log_format;
+///
+/// set_log_format(|f, prefix, err| write!(f, "{prefix}error=\"{err}\"")).unwrap();
+/// ```
+pub fn set_log_format(
+    format: impl Fn(&mut Formatter<'_>, &dyn Display, &dyn Display) -> std::fmt::Result
+        + Send
+        + Sync
+        + 'static,
+) -> Result<(), SetLogFormatError> {
+    FORMAT.set(Box::new(format)).map_err(|_| SetLogFormatError)
+}
+
+/// Same as `set_log_format`, but takes a template, where `{prefix}`
+/// and `{err}` are replaced with the prefix and the error.
+/// Other placeholders are rejected, `{{` and `}}` stand for literal braces.
+/// Everything else is kept as is.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Arguments;
+/// use std::sync::Mutex;
+/// use error_traits::{set_global_sink, set_log_template, ErrSink, LogErr};
+///
+/// static LINES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// struct Capture;
+///
+/// impl ErrSink for Capture {
+///     fn emit(&self, _target: &str, _level: log::Level, msg: Arguments<'_>) {
+///         LINES.lock().unwrap().push(msg.to_string())
+///     }
+/// }
+///
+/// set_global_sink(Capture).unwrap();
+/// set_log_template("{prefix}{err} (service=billing)").unwrap();
+///
+/// let _ = "foo".parse::<u16>().log_err("parsing port: ");
+/// // With `log_location` feature, the prefix also starts with location of the call.
+/// assert!(LINES.lock().unwrap()[0]
+///     .ends_with("parsing port: invalid digit found in string (service=billing)"));
+///
+/// let error = set_log_template("{level} {err}").unwrap_err();
+/// assert_eq!(error.to_string(), "unknown placeholder `{level}` in log template");
+/// ```
+pub fn set_log_template(template: &str) -> Result<(), SetLogTemplateError> {
+    let segments = parse_template(template)?;
+    set_log_format(move |f, prefix, err| {
+        segments.iter().try_for_each(|segment| match segment {
+            Segment::Text(text) => f.write_str(text),
+            Segment::Prefix => Display::fmt(prefix, f),
+            Segment::Err => Display::fmt(err, f),
+        })
+    })
+    .map_err(|_| SetLogTemplateError::AlreadyInstalled)
+}
+
+enum Segment {
+    Text(String),
+    Prefix,
+    Err,
+}
+
+fn parse_template(template: &str) -> Result<Vec<Segment>, SetLogTemplateError> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = template;
+    while let Some(at) = rest.find(['{', '}']) {
+        text.push_str(&rest[..at]);
+        rest = &rest[at..];
+        if let Some(after) = rest.strip_prefix("{{").or_else(|| rest.strip_prefix("}}")) {
+            text.push_str(&rest[..1]);
+            rest = after;
+            continue;
+        }
+        let (segment, after) = if let Some(after) = rest.strip_prefix("{prefix}") {
+            (Segment::Prefix, after)
+        } else if let Some(after) = rest.strip_prefix("{err}") {
+            (Segment::Err, after)
+        } else {
+            let end = rest.find('}').map_or(rest.len(), |at| at + 1);
+            return Err(SetLogTemplateError::UnknownPlaceholder(
+                rest[..end].to_owned(),
+            ));
+        };
+        if !text.is_empty() {
+            segments.push(Segment::Text(std::mem::take(&mut text)));
+        }
+        segments.push(segment);
+        rest = after;
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+/// Message of a logging trait, formatted with the global format.
+pub(crate) struct LogMessage<'a> {
+    pub(crate) prefix: &'a dyn Display,
+    pub(crate) err: &'a dyn Display,
+}
+
+impl Display for LogMessage<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match FORMAT.get() {
+            Some(format) => format(f, self.prefix, self.err),
+            None => write!(f, "{}{}", self.prefix, self.err),
+        }
+    }
+}
+
+/// Returned by `set_log_format` if global format is already installed.
+#[derive(Debug)]
+pub struct SetLogFormatError;
+
+impl Display for SetLogFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("log format is already installed")
+    }
+}
+
+impl Error for SetLogFormatError {}
+
+/// Returned by `set_log_template`.
+#[derive(Debug)]
+pub enum SetLogTemplateError {
+    /// Template has a placeholder other than `{prefix}` and `{err}`.
+    UnknownPlaceholder(String),
+    /// Global format is already installed.
+    AlreadyInstalled,
+}
+
+impl Display for SetLogTemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetLogTemplateError::UnknownPlaceholder(placeholder) => {
+                write!(f, "unknown placeholder `{placeholder}` in log template")
+            }
+            SetLogTemplateError::AlreadyInstalled => Display::fmt(&SetLogFormatError, f),
+        }
+    }
+}
+
+impl Error for SetLogTemplateError {}
@@ -0,0 +1,135 @@
+/// Same as `LogErr::log_err`, but takes `format!`-style arguments for the prefix,
+/// which is only formatted if the result is an error.
+/// Level can be given with `level = ...` before the result.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::log_err;
+///
+/// let path = "missing/config.toml";
+/// let config = log_err!(std::fs::read_to_string(path), "failed to load {path}: ");
+/// let config = log_err!(level = log::Level::Warn, std::fs::read_to_string(path), "failed to load {}: ", path);
+/// ```
+#[macro_export]
+macro_rules! log_err {
+    (level = $level:expr, $result:expr, $($arg:tt)+) => {
+        $crate::LogErr::log_err_target_at_with(
+            $result,
+            $crate::__log_err_target!(),
+            $level,
+            || ::std::format!($($arg)+),
+        )
+    };
+    ($result:expr, $($arg:tt)+) => {
+        // `SeverityLevel` converts into `log::Level`, which callers may not depend on.
+        $crate::log_err!(
+            level = ::core::convert::Into::into($crate::SeverityLevel::Error),
+            $result,
+            $($arg)+
+        )
+    };
+}
+
+/// Target of `log_err!` records: caller's module with `log_location` feature,
+/// same as `LogErr` otherwise.
+#[doc(hidden)]
+#[cfg(feature = "log_location")]
+#[macro_export]
+macro_rules! __log_err_target {
+    () => {
+        ::core::module_path!()
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "log_location"))]
+#[macro_export]
+macro_rules! __log_err_target {
+    () => {
+        "error_traits::log_err"
+    };
+}
+
+/// Logs an error returned from a function once, through `LogErr`,
+/// prefixed with module path, function name and its arguments.
+/// Works for both sync and `async` functions returning `Result`.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::log_errors;
+///
+/// // Logs "<module path>::load(path: &str): No such file or directory (os error 2)".
+/// #[log_errors]
+/// fn load(path: &str) -> std::io::Result<String> {
+///     std::fs::read_to_string(path)
+/// }
+///
+/// #[log_errors]
+/// async fn parse(number: String) -> Result<u16, std::num::ParseIntError> {
+///     let number = number.trim().parse()?;
+///     Ok(number)
+/// }
+///
+/// #[log_errors]
+/// fn digits(number: &str) -> Result<impl Iterator<Item = u32> + '_, std::num::ParseIntError> {
+///     if number.is_empty() {
+///         return Ok(None.into_iter().flatten());
+///     }
+///     number.parse::<u32>()?;
+///     Ok(Some(number.chars().filter_map(|c| c.to_digit(10))).into_iter().flatten())
+/// }
+///
+/// #[log_errors]
+/// fn first(numbers: &mut [u16]) -> Result<&mut u16, &'static str> {
+///     numbers.first_mut().ok_or("empty")
+/// }
+///
+/// assert!(load("missing/config.toml").is_err());
+/// assert!(futures::executor::block_on(parse(" 42 ".to_owned())).is_ok());
+/// assert_eq!(digits("123").unwrap().collect::<Vec<_>>(), [1, 2, 3]);
+/// assert_eq!(digits("").unwrap().count(), 0);
+/// assert!(digits("foo").is_err());
+/// assert_eq!(first(&mut [1, 2]), Ok(&mut 1));
+/// ```
+pub use error_traits_macros::log_errors;
+
+/// Calls `f`, used by `log_errors` to make its closure `FnOnce`.
+#[doc(hidden)]
+pub fn __call_once<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+/// Implements `ErrorCode`, `Severity` and `HttpStatus` (with `http` feature)
+/// from `#[error_meta(...)]` attributes, instead of three hand-written `match`es.
+/// Attributes on the type itself act as defaults for its variants.
+/// A trait is only implemented if every variant has a value for it.
+///
+/// Supported keys are `code = "..."`, `severity = "info" | "warn" | "error" | "fatal"`
+/// and `status = 100..=999`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{ErrorCode, ErrorMeta, Severity, SeverityLevel};
+///
+/// #[derive(Debug, ErrorMeta)]
+/// #[error_meta(severity = "error")]
+/// enum DbError {
+///     #[error_meta(code = "E1001", severity = "warn")]
+///     Timeout,
+///     #[error_meta(code = "E1002")]
+///     NotFound { table: String },
+///     #[error_meta(code = "E1003", severity = "fatal")]
+///     Corrupted(u64),
+/// }
+///
+/// assert_eq!(DbError::Timeout.code(), "E1001");
+/// assert_eq!(DbError::Timeout.severity(), SeverityLevel::Warning);
+/// assert_eq!(DbError::NotFound { table: "users".to_owned() }.severity(), SeverityLevel::Error);
+/// assert_eq!(DbError::Corrupted(7).severity(), SeverityLevel::Fatal);
+/// ```
+pub use error_traits_macros::ErrorMeta;
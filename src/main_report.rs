@@ -0,0 +1,68 @@
+use crate::IntoExitCode;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Error type for `fn main() -> Result<(), MainReport>`.
+/// Any error converts into it with `?`, and when `main` fails, the whole
+/// chain is printed (see `ChainExt::display_chain`) instead of derived `Debug` output.
+/// With `color` feature, the chain is rendered with `DisplayPrettyExt::display_pretty`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use error_traits::{MainReport, WithContext};
+///
+/// fn main() -> Result<(), MainReport> {
+///     let config = std::fs::read_to_string("config.toml").context("reading config")?;
+///     println!("{config}");
+///     Ok(())
+/// }
+/// ```
+pub struct MainReport {
+    error: Box<dyn Error + Send + Sync + 'static>,
+}
+
+impl MainReport {
+    pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.error
+    }
+
+    pub fn into_inner(self) -> Box<dyn Error + Send + Sync + 'static> {
+        self.error
+    }
+}
+
+impl<E> From<E> for MainReport
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn from(error: E) -> Self {
+        Self {
+            error: Box::new(error),
+        }
+    }
+}
+
+impl Debug for MainReport {
+    #[cfg(not(feature = "color"))]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use crate::ChainExt;
+
+        Display::fmt(&self.error.display_chain(), f)
+    }
+
+    #[cfg(feature = "color")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use crate::DisplayPrettyExt;
+
+        Display::fmt(&self.error.display_pretty(), f)
+    }
+}
+
+impl Display for MainReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl IntoExitCode for MainReport {}
@@ -0,0 +1,42 @@
+use crate::StdResult;
+use metrics::SharedString;
+
+/// If error is present, increments a `metrics` counter and returns it back.
+/// Same tap style as `PassErrWith`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::CountErr;
+///
+/// let number = "foo".parse::<u16>().count_err("parse_errors");
+/// let number = "foo"
+///     .parse::<u16>()
+///     .count_err_labeled("parse_errors", &[("kind", "port")]);
+/// ```
+pub trait CountErr
+where
+    Self: Sized,
+{
+    fn count_err_labeled<K, V>(self, name: &'static str, labels: &[(K, V)]) -> Self
+    where
+        K: Into<SharedString> + Clone,
+        V: Into<SharedString> + Clone;
+
+    fn count_err(self, name: &'static str) -> Self {
+        self.count_err_labeled::<&str, &str>(name, &[])
+    }
+}
+
+impl<T, E> CountErr for StdResult<T, E> {
+    fn count_err_labeled<K, V>(self, name: &'static str, labels: &[(K, V)]) -> Self
+    where
+        K: Into<SharedString> + Clone,
+        V: Into<SharedString> + Clone,
+    {
+        if self.is_err() {
+            metrics::counter!(name, labels).increment(1)
+        }
+        self
+    }
+}
@@ -0,0 +1,139 @@
+use crate::StdResult;
+use miette::{Diagnostic, LabeledSpan, SourceCode, SourceSpan};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Error turned into a `miette::Diagnostic`, with optional help text and labels.
+/// Displays the same as wrapped error and keeps its `source()`.
+#[derive(Debug)]
+pub struct Diagnosed<E> {
+    error: E,
+    help: Option<String>,
+    labels: Vec<LabeledSpan>,
+}
+
+impl<E> Diagnosed<E> {
+    pub fn new(error: E) -> Self {
+        Self {
+            error,
+            help: None,
+            labels: Vec::new(),
+        }
+    }
+
+    pub fn with_help(self, help: impl Into<String>) -> Self {
+        Self {
+            help: Some(help.into()),
+            ..self
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<LabeledSpan>) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for Diagnosed<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E> Error for Diagnosed<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> Diagnostic for Diagnosed<E>
+where
+    E: Error,
+{
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        if self.labels.is_empty() {
+            return None;
+        }
+        Some(Box::new(self.labels.iter().cloned()))
+    }
+}
+
+/// Conversion of usual results to `miette::Result`, so diagnostics
+/// render with miette's fancy output.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::IntoMiette;
+///
+/// let res: miette::Result<u16> = "foo".parse::<u16>().into_miette();
+/// let res: miette::Result<u16> = "foo".parse::<u16>().with_help("port must be a number");
+///
+/// let input = "port = foo";
+/// let res: miette::Result<u16> = input[7..]
+///     .parse::<u16>()
+///     .with_source_span(input, (7, 3), "not a number");
+/// ```
+pub trait IntoMiette<T> {
+    fn into_miette(self) -> miette::Result<T>;
+
+    fn with_help(self, help: impl Into<String>) -> miette::Result<T>;
+
+    /// Attaches `source_code`, with `span` of it labeled by `label`.
+    fn with_source_span<S>(
+        self,
+        source_code: S,
+        span: impl Into<SourceSpan>,
+        label: impl Into<String>,
+    ) -> miette::Result<T>
+    where
+        S: SourceCode + 'static;
+}
+
+impl<T, E> IntoMiette<T> for StdResult<T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn into_miette(self) -> miette::Result<T> {
+        self.map_err(|e| miette::Report::new(Diagnosed::new(e)))
+    }
+
+    fn with_help(self, help: impl Into<String>) -> miette::Result<T> {
+        self.map_err(|e| miette::Report::new(Diagnosed::new(e).with_help(help)))
+    }
+
+    fn with_source_span<S>(
+        self,
+        source_code: S,
+        span: impl Into<SourceSpan>,
+        label: impl Into<String>,
+    ) -> miette::Result<T>
+    where
+        S: SourceCode + 'static,
+    {
+        self.map_err(|e| {
+            let diagnosed = Diagnosed::new(e).with_label(LabeledSpan::at(span, label));
+            miette::Report::new(diagnosed).with_source_code(source_code)
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Accumulates many errors to surface them as one.
+/// Can be `collect()`-ed from an iterator of errors.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::MultiError;
+///
+/// let mut errors = MultiError::new();
+/// for s in ["1", "foo", "bar"] {
+///     if let Err(e) = s.parse::<u16>() {
+///         errors.push(e);
+///     }
+/// }
+/// assert_eq!(errors.len(), 2);
+/// assert!(errors.into_result().is_err());
+///
+/// let errors: MultiError<String> = vec!["a".to_owned(), "b".to_owned()].into_iter().collect();
+/// assert_eq!(errors.to_string(), "2 errors occurred:\n  1. a\n  2. b");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiError<E> {
+    errors: Vec<E>,
+}
+
+impl<E> MultiError<E> {
+    pub fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    pub fn push(&mut self, error: E) {
+        self.errors.push(error)
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, E> {
+        self.errors.iter()
+    }
+
+    pub fn into_vec(self) -> Vec<E> {
+        self.errors
+    }
+
+    /// `Ok(())` if there are no errors, `Err(self)` otherwise.
+    pub fn into_result(self) -> StdResult<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<E> Default for MultiError<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> From<Vec<E>> for MultiError<E> {
+    fn from(errors: Vec<E>) -> Self {
+        Self { errors }
+    }
+}
+
+impl<E> FromIterator<E> for MultiError<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        Self {
+            errors: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<E> Extend<E> for MultiError<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        self.errors.extend(iter)
+    }
+}
+
+impl<E> IntoIterator for MultiError<E> {
+    type Item = E;
+    type IntoIter = std::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a MultiError<E> {
+    type Item = &'a E;
+    type IntoIter = std::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl<E> Display for MultiError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.errors.len() {
+            1 => write!(f, "1 error occurred:")?,
+            n => write!(f, "{n} errors occurred:")?,
+        }
+        for (i, e) in self.errors.iter().enumerate() {
+            write!(f, "\n  {}. {e}", i + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> Error for MultiError<E> where E: Debug + Display {}
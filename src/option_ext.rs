@@ -0,0 +1,162 @@
+use crate::{ResultLike, StdResult};
+
+/// Turns `None` into an error produced by `f`, ignoring everything else.
+/// Counterpart of `MapErrBy` for `Option`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{OkOrBy, TestError};
+///
+/// let error = || TestError::new("missing");
+/// let number: Result<u32, TestError> = Some(42).ok_or_by(error);
+/// ```
+pub trait OkOrBy<T, E> {
+    fn ok_or_by(self, f: impl FnOnce() -> E) -> StdResult<T, E>;
+}
+
+impl<R, E> OkOrBy<R::Ok, E> for R
+where
+    R: ResultLike<Err = ()>,
+{
+    fn ok_or_by(self, f: impl FnOnce() -> E) -> StdResult<R::Ok, E> {
+        self.into_result().map_err(|()| f())
+    }
+}
+
+/// Calls `f` if `Option` is `None` and returns it back.
+/// Meant to be used for logging, or something alike.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::PassNoneWith;
+///
+/// let value = None::<u16>.pass_none_with(|| println!("[:: LOG ::] value is missing"));
+/// ```
+pub trait PassNoneWith {
+    fn pass_none_with(self, f: impl FnOnce()) -> Self;
+}
+
+impl<T> PassNoneWith for Option<T> {
+    fn pass_none_with(self, f: impl FnOnce()) -> Self {
+        if self.is_none() {
+            f()
+        }
+        self
+    }
+}
+
+/// If value is `None`, this trait logs `log_msg` and returns it back.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::LogNone;
+///
+/// let value = std::env::var("FOO").ok().log_none("env var `FOO` is not set");
+/// ```
+#[cfg(feature = "log_err")]
+pub trait LogNone {
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_none(self, log_msg: &str) -> Self;
+}
+
+#[cfg(feature = "log_err")]
+impl<T> LogNone for Option<T> {
+    fn log_none(self, log_msg: &str) -> Self {
+        let level = log::Level::Error;
+        let target = crate::log_err::TARGET;
+        if self.is_none() && crate::global_sink().enabled(target, level) {
+            let location = std::panic::Location::caller();
+            crate::log_err::emit(location, target, level, &"", &log_msg)
+        }
+        self
+    }
+}
+
+/// Turns `None` into an error, logging it on the way, so missing values
+/// are noticed where they are missing, not somewhere later.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::OkOrLog;
+///
+/// let value: Result<String, String> = std::env::var("FOO")
+///     .ok()
+///     .ok_or_log("config: ", || "env var `FOO` is not set".to_owned());
+/// let value: Result<String, String> = std::env::var("FOO").ok().some_or_log("env var `FOO` is not set");
+/// ```
+#[cfg(feature = "log_err")]
+pub trait OkOrLog<T> {
+    /// Builds an error with `f`, logs it after `log_prefix` and returns it.
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn ok_or_log<E>(self, log_prefix: &str, f: impl FnOnce() -> E) -> StdResult<T, E>
+    where
+        E: core::fmt::Display;
+
+    /// Logs `log_msg` and returns it as an error.
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn some_or_log(self, log_msg: &str) -> StdResult<T, String>;
+}
+
+#[cfg(feature = "log_err")]
+impl<T> OkOrLog<T> for Option<T> {
+    fn ok_or_log<E>(self, log_prefix: &str, f: impl FnOnce() -> E) -> StdResult<T, E>
+    where
+        E: core::fmt::Display,
+    {
+        use crate::LogErr;
+
+        self.ok_or_else(f).log_err(log_prefix)
+    }
+
+    fn some_or_log(self, log_msg: &str) -> StdResult<T, String> {
+        self.log_none(log_msg).ok_or_else(|| log_msg.to_owned())
+    }
+}
+
+/// Turns `Ok(None)` into an error produced by `f`, leaving other errors as is.
+/// Meant for "fetch optional" calls, where a missing value is an error too.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::Require;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum DbError {
+///     NotFound,
+/// }
+///
+/// let fetched: Result<Option<u32>, DbError> = Ok(None);
+/// assert_eq!(fetched.require(|| DbError::NotFound), Err(DbError::NotFound));
+///
+/// let fetched: Result<Option<u32>, DbError> = Ok(Some(42));
+/// assert_eq!(fetched.require(|| DbError::NotFound), Ok(42));
+/// ```
+pub trait Require<T, E>
+where
+    Self: Sized,
+{
+    fn require(self, f: impl FnOnce() -> E) -> StdResult<T, E>;
+
+    /// Same as `require`, but logs `log_msg` if value is missing.
+    /// Requires an initialized logger.
+    #[cfg(feature = "log_err")]
+    fn require_or_log(self, log_msg: &str, f: impl FnOnce() -> E) -> StdResult<T, E>;
+}
+
+impl<T, E> Require<T, E> for StdResult<Option<T>, E> {
+    fn require(self, f: impl FnOnce() -> E) -> StdResult<T, E> {
+        self.and_then(|value| value.ok_or_else(f))
+    }
+
+    #[cfg(feature = "log_err")]
+    fn require_or_log(self, log_msg: &str, f: impl FnOnce() -> E) -> StdResult<T, E> {
+        self.and_then(|value| value.log_none(log_msg).ok_or_else(f))
+    }
+}
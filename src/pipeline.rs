@@ -0,0 +1,199 @@
+use crate::{Classified, Contextualized, ErrorClass, StdResult};
+use std::borrow::Cow;
+use std::error::Error;
+use std::panic::Location;
+
+/// Reusable sequence of error-handling steps (logging, reporting, classifying,
+/// mapping, adding context), built once, stored anywhere and applied with `ApplyPipeline::apply`.
+/// Steps run in order they were added, and only on the error path.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::ParseIntError;
+/// use error_traits::{ApplyPipeline, ErrPipeline};
+///
+/// #[derive(Debug)]
+/// struct AppError(String);
+///
+/// impl From<ParseIntError> for AppError {
+///     fn from(e: ParseIntError) -> Self {
+///         AppError(e.to_string())
+///     }
+/// }
+///
+/// struct Parser {
+///     on_err: ErrPipeline<ParseIntError, AppError>,
+/// }
+///
+/// let parser = Parser {
+///     on_err: ErrPipeline::new()
+///         .tap(|e| println!("[:: LOG ::] {e}"))
+///         .map_to::<AppError>(),
+/// };
+/// let res: Result<u16, AppError> = "foo".parse::<u16>().apply(&parser.on_err);
+/// ```
+pub struct ErrPipeline<E, N = E> {
+    run: Box<dyn Fn(E) -> N + Send + Sync>,
+}
+
+impl<E> ErrPipeline<E>
+where
+    E: 'static,
+{
+    /// Pipeline which returns error as is.
+    pub fn new() -> Self {
+        Self {
+            run: Box::new(|e| e),
+        }
+    }
+}
+
+impl<E> Default for ErrPipeline<E>
+where
+    E: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, N> ErrPipeline<E, N>
+where
+    E: 'static,
+    N: 'static,
+{
+    /// Runs the pipeline on `error`.
+    pub fn run(&self, error: E) -> N {
+        (self.run)(error)
+    }
+
+    /// Calls `f` with the current error.
+    pub fn tap(self, f: impl Fn(&N) + Send + Sync + 'static) -> Self {
+        let run = self.run;
+        Self {
+            run: Box::new(move |e| {
+                let n = run(e);
+                f(&n);
+                n
+            }),
+        }
+    }
+
+    /// Replaces the current error with the result of `f`.
+    pub fn map<M>(self, f: impl Fn(N) -> M + Send + Sync + 'static) -> ErrPipeline<E, M>
+    where
+        M: 'static,
+    {
+        let run = self.run;
+        ErrPipeline {
+            run: Box::new(move |e| f(run(e))),
+        }
+    }
+
+    /// Converts the current error with `From`.
+    pub fn map_to<M>(self) -> ErrPipeline<E, M>
+    where
+        M: From<N> + 'static,
+    {
+        self.map(M::from)
+    }
+
+    /// Wraps the current error into `Contextualized`.
+    pub fn context(self, context: impl Into<Cow<'static, str>>) -> ErrPipeline<E, Contextualized<N>>
+    where
+        N: Error,
+    {
+        let context = context.into();
+        self.map(move |n| Contextualized::new(context.clone(), n))
+    }
+
+    /// Pairs the current error with its `ErrorClass::class`, so that later steps
+    /// (and the final error type) can tell whether it is worth retrying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use error_traits::{ApplyPipeline, Classified, ErrPipeline, ErrorClass};
+    ///
+    /// #[derive(Debug)]
+    /// struct AppError {
+    ///     retry: bool,
+    /// }
+    ///
+    /// impl From<Classified<io::Error>> for AppError {
+    ///     fn from(e: Classified<io::Error>) -> Self {
+    ///         AppError { retry: e.is_transient() }
+    ///     }
+    /// }
+    ///
+    /// let on_err = ErrPipeline::<io::Error>::new().classify().map_to::<AppError>();
+    /// let res: Result<(), _> = Err(io::Error::from(io::ErrorKind::TimedOut)).apply(&on_err);
+    /// assert!(res.unwrap_err().retry);
+    /// ```
+    pub fn classify(self) -> ErrPipeline<E, Classified<N>>
+    where
+        N: ErrorClass,
+    {
+        self.map(Classified::new)
+    }
+
+    /// Invokes hooks installed with `install_error_hook`.
+    /// Location passed to hooks is the one this step was added at.
+    #[track_caller]
+    pub fn report(self) -> Self
+    where
+        N: Error,
+    {
+        let location = Location::caller();
+        self.tap(move |n| crate::hooks::call_hooks(n, location))
+    }
+
+    /// Increments counter `name` using `CountErr`.
+    #[cfg(feature = "metrics")]
+    pub fn count_err(self, name: &'static str) -> Self {
+        use crate::CountErr;
+
+        self.tap(move |n| {
+            let _ = Err::<(), _>(n).count_err(name);
+        })
+    }
+
+    /// Logs the current error using `LogErr`.
+    /// With `log_location` feature, location is the one this step was added at.
+    #[cfg(feature = "log_err")]
+    #[cfg_attr(feature = "log_location", track_caller)]
+    pub fn log(self, log_prefix: impl Into<Cow<'static, str>>) -> Self
+    where
+        N: std::fmt::Display,
+    {
+        use crate::log_err::{log_err_from, TARGET};
+
+        let log_prefix = log_prefix.into();
+        let location = Location::caller();
+        self.tap(move |n| {
+            let _ = log_err_from(Err::<(), _>(n), location, TARGET, log::Level::Error, || {
+                &log_prefix
+            });
+        })
+    }
+}
+
+/// Applies `ErrPipeline` to the error (if any).
+pub trait ApplyPipeline<T, E> {
+    fn apply<N>(self, pipeline: &ErrPipeline<E, N>) -> StdResult<T, N>
+    where
+        E: 'static,
+        N: 'static;
+}
+
+impl<T, E> ApplyPipeline<T, E> for StdResult<T, E> {
+    fn apply<N>(self, pipeline: &ErrPipeline<E, N>) -> StdResult<T, N>
+    where
+        E: 'static,
+        N: 'static,
+    {
+        self.map_err(|e| pipeline.run(e))
+    }
+}
@@ -0,0 +1,76 @@
+pub use crate::{
+    ErrInto, FlattenErrInto, FromControlFlow, IntoControlFlow, MapErrBy, MapErrByWith, MergeInto,
+    MergeOkErr, OkOrBy, PassErrWith, PassNoneWith, PassOkWith, PassWith, ResultLike, Severity,
+    UnwrapInfallible,
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::{MapErrToCow, MapErrToDebugString, MapErrToStrOrDebug, MapErrToString};
+
+#[cfg(feature = "std")]
+pub use crate::{
+    AndMeta, ApplyPipeline, AttachMeta, ChainExt, ErrBoxed, ErrHere, ErrorClass, ErrorCode,
+    FfiError, Fingerprint, HandledErr, IntoExitCode, IoErrExt, IterResultExt, LockResultExt,
+    MapErrChainToString, MapErrToRootString, MapErrToTruncatedString, RecoverIf, RecoverIfDyn,
+    Redact, ReportErr, Require, TupleResults, WithCode, WithContext, WithCorrelationId,
+};
+
+#[cfg(feature = "log_err")]
+pub use crate::{
+    ClearPoisonLog, ErrSink, GuardErr, LogErr, LogErrBySeverity, LogErrCoded, LogErrDebug,
+    LogErrEvery, LogErrIf, LogErrPretty, LogErrTruncated, LogNone, OkOrLog, OrTry, RecvOrLog,
+    SendOrLog, UnwrapOrLog, UnwrapOrLogged,
+};
+
+#[cfg(feature = "backtrace")]
+pub use crate::CaptureBacktrace;
+#[cfg(feature = "sentry")]
+pub use crate::CaptureErr;
+#[cfg(all(feature = "wasm_console", target_arch = "wasm32"))]
+pub use crate::ConsoleErr;
+#[cfg(feature = "metrics")]
+pub use crate::CountErr;
+#[cfg(feature = "color")]
+pub use crate::DisplayPrettyExt;
+#[cfg(feature = "test_utils")]
+pub use crate::ExpectErrChain;
+#[cfg(feature = "tokio")]
+pub use crate::FlattenJoin;
+#[cfg(feature = "future_ext")]
+pub use crate::FutureResultExt;
+#[cfg(feature = "anyhow")]
+pub use crate::IntoAnyhow;
+#[cfg(feature = "axum")]
+pub use crate::IntoApiErr;
+#[cfg(feature = "eyre")]
+pub use crate::IntoEyre;
+#[cfg(feature = "miette")]
+pub use crate::IntoMiette;
+#[cfg(feature = "defmt")]
+pub use crate::LogErrDefmt;
+#[cfg(all(feature = "errno", unix))]
+pub use crate::MapErrToErrno;
+#[cfg(feature = "rayon")]
+pub use crate::ParallelResultExt;
+#[cfg(feature = "retry")]
+pub use crate::RetryOnErr;
+#[cfg(feature = "stream_ext")]
+pub use crate::StreamResultExt;
+#[cfg(feature = "serde")]
+pub use crate::ToSerializableError;
+#[cfg(feature = "tracing_err")]
+pub use crate::TraceErr;
+#[cfg(feature = "spantrace")]
+pub use crate::WithSpanTrace;
+#[cfg(feature = "error_stack_dyn_ext")]
+pub use crate::{
+    ChangeContextBy, CollectReports, ConvReport, ExtendReport, IntoReportDyn, ReportIntoDyn,
+};
+#[cfg(feature = "either")]
+pub use crate::{EitherIntoResult, ResultIntoEither};
+#[cfg(feature = "tonic")]
+pub use crate::{GrpcCode, MapErrToGrpcStatus};
+#[cfg(feature = "http")]
+pub use crate::{HttpStatus, MapErrToStatus};
+#[cfg(feature = "log_err_kv")]
+pub use crate::{LogErrKv, LogErrMeta};
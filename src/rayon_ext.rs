@@ -0,0 +1,54 @@
+use crate::{IterResultExt, MultiError, StdResult};
+use rayon::iter::ParallelIterator;
+
+/// Same as `IterResultExt`, but for parallel iterators over `Result`s.
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+/// use error_traits::ParallelResultExt;
+///
+/// let all = ["1", "foo", "bar"].par_iter().map(|s| s.parse::<u16>()).collect_all_errors();
+/// assert_eq!(all.unwrap_err().len(), 2);
+///
+/// let all = ["1", "2", "3"].par_iter().map(|s| s.parse::<u16>()).collect_all_errors();
+/// assert_eq!(all.unwrap(), [1, 2, 3]);
+/// ```
+pub trait ParallelResultExt<T, E>
+where
+    Self: ParallelIterator<Item = StdResult<T, E>>,
+    T: Send,
+    E: Send,
+{
+    /// Unlike `collect::<Result<_, _>>()`, doesn't stop at the first error,
+    /// but returns every one of them. Order of items is kept.
+    fn collect_all_errors(self) -> StdResult<Vec<T>, MultiError<E>> {
+        self.collect::<Vec<_>>().into_iter().try_collect_all()
+    }
+
+    /// Collects successes, logging every error using `LogErr`.
+    /// Requires an initialized logger.
+    #[cfg(feature = "log_err")]
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn collect_oks_log_errs(self, log_prefix: &str) -> Vec<T>
+    where
+        E: std::fmt::Display,
+    {
+        use crate::log_err::{log_err_from, TARGET};
+
+        let location = std::panic::Location::caller();
+        self.filter_map(|res| {
+            log_err_from(res, location, TARGET, log::Level::Error, || log_prefix).ok()
+        })
+        .collect()
+    }
+}
+
+impl<I, T, E> ParallelResultExt<T, E> for I
+where
+    I: ParallelIterator<Item = StdResult<T, E>>,
+    T: Send,
+    E: Send,
+{
+}
@@ -0,0 +1,65 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+static REDACTOR: OnceLock<Box<dyn Redact + Send + Sync>> = OnceLock::new();
+
+/// Scrubs sensitive data (emails, tokens, SQL literals) from error messages
+/// before they reach logs.
+/// Implemented for any `Fn(&str) -> String`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::Redact;
+///
+/// let mask_digits = |msg: &str| msg.replace(|c: char| c.is_ascii_digit(), "*");
+/// assert_eq!(mask_digits.redact("card 1234"), "card ****");
+/// ```
+pub trait Redact {
+    fn redact(&self, msg: &str) -> String;
+}
+
+impl<F> Redact for F
+where
+    F: Fn(&str) -> String,
+{
+    fn redact(&self, msg: &str) -> String {
+        self(msg)
+    }
+}
+
+/// Installs global redactor, used by `LogErr`, `LogErrEvery` and `LogErrKv` unless
+/// a redactor is given explicitly.
+/// Can only be called once, subsequent calls fail.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::set_redactor;
+///
+/// set_redactor(|msg: &str| msg.replace("secret", "******")).unwrap();
+/// assert!(set_redactor(|msg: &str| msg.to_owned()).is_err());
+/// ```
+pub fn set_redactor(redactor: impl Redact + Send + Sync + 'static) -> Result<(), SetRedactorError> {
+    REDACTOR
+        .set(Box::new(redactor))
+        .map_err(|_| SetRedactorError)
+}
+
+/// Returns global redactor, if it was installed with `set_redactor`.
+pub fn redactor() -> Option<&'static (dyn Redact + Send + Sync)> {
+    REDACTOR.get().map(|r| &**r)
+}
+
+/// Returned by `set_redactor` if global redactor is already installed.
+#[derive(Debug)]
+pub struct SetRedactorError;
+
+impl Display for SetRedactorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("redactor is already installed")
+    }
+}
+
+impl Error for SetRedactorError {}
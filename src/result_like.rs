@@ -0,0 +1,131 @@
+use crate::StdResult;
+use core::task::Poll;
+
+/// Container that is either a success or a failure: `Result`, `Option`
+/// (with `()` as an error), `Poll<Result>` or `Poll<Option<Result>>`
+/// (with `Pending` and end of stream as a success, the same way `?` treats them).
+/// Combinators of the crate (`MapErrBy`, `MapErrToString`, `PassErrWith`, `LogErr`, etc.)
+/// are implemented for every `ResultLike`, and ones that change the error
+/// return the same container with the new error type (see `WithErr`),
+/// so hand-written `poll` implementations can use them without nested matches.
+///
+/// # Examples
+///
+/// ```
+/// use std::task::Poll;
+/// use error_traits::{MapErrBy, PassErrWith, ResultLike, TestError};
+///
+/// fn or_missing<R>(value: R) -> Result<R::Ok, &'static str>
+/// where
+///     R: ResultLike,
+/// {
+///     value.into_result().map_err_by(|| "missing")
+/// }
+///
+/// assert_eq!(or_missing(Some(1)), Ok(1));
+/// assert_eq!(or_missing(None::<u8>), Err("missing"));
+/// assert_eq!(or_missing("foo".parse::<u8>()), Err("missing"));
+/// assert_eq!(or_missing(Poll::<Result<u8, ()>>::Pending), Ok(Poll::Pending));
+///
+/// let mut seen = None;
+/// let poll = Poll::Ready(Some("foo".parse::<u8>()))
+///     .pass_err_with(|e| seen = Some(e.clone()))
+///     .map_err_by(|| TestError::new("not a number"));
+/// assert_eq!(poll, Poll::Ready(Some(Err("not a number".into()))));
+/// assert!(seen.is_some());
+///
+/// let back = Option::from_result(Ok::<_, ()>(1));
+/// assert_eq!(back, Some(1));
+/// ```
+pub trait ResultLike
+where
+    Self: Sized,
+{
+    type Ok;
+    type Err;
+    /// Same container, but with `N` as an error.
+    /// `Option<T>` becomes `Result<T, N>`, since it has no place for an error.
+    type WithErr<N>: ResultLike<Ok = Self::Ok, Err = N>;
+
+    fn into_result(self) -> StdResult<Self::Ok, Self::Err>;
+
+    fn from_result(result: StdResult<Self::Ok, Self::Err>) -> Self;
+
+    /// Same as `from_result`, but builds the container with another error type.
+    fn from_result_with<N>(result: StdResult<Self::Ok, N>) -> Self::WithErr<N> {
+        Self::WithErr::<N>::from_result(result)
+    }
+}
+
+impl<T, E> ResultLike for StdResult<T, E> {
+    type Ok = T;
+    type Err = E;
+    type WithErr<N> = StdResult<T, N>;
+
+    fn into_result(self) -> Self {
+        self
+    }
+
+    fn from_result(result: Self) -> Self {
+        result
+    }
+}
+
+impl<T> ResultLike for Option<T> {
+    type Ok = T;
+    type Err = ();
+    type WithErr<N> = StdResult<T, N>;
+
+    fn into_result(self) -> StdResult<T, ()> {
+        self.ok_or(())
+    }
+
+    fn from_result(result: StdResult<T, ()>) -> Self {
+        result.ok()
+    }
+}
+
+impl<T, E> ResultLike for Poll<StdResult<T, E>> {
+    type Ok = Poll<T>;
+    type Err = E;
+    type WithErr<N> = Poll<StdResult<T, N>>;
+
+    fn into_result(self) -> StdResult<Poll<T>, E> {
+        match self {
+            Poll::Ready(Ok(t)) => Ok(Poll::Ready(t)),
+            Poll::Ready(Err(e)) => Err(e),
+            Poll::Pending => Ok(Poll::Pending),
+        }
+    }
+
+    fn from_result(result: StdResult<Poll<T>, E>) -> Self {
+        match result {
+            Ok(Poll::Ready(t)) => Poll::Ready(Ok(t)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+impl<T, E> ResultLike for Poll<Option<StdResult<T, E>>> {
+    type Ok = Poll<Option<T>>;
+    type Err = E;
+    type WithErr<N> = Poll<Option<StdResult<T, N>>>;
+
+    fn into_result(self) -> StdResult<Poll<Option<T>>, E> {
+        match self {
+            Poll::Ready(Some(Ok(t))) => Ok(Poll::Ready(Some(t))),
+            Poll::Ready(Some(Err(e))) => Err(e),
+            Poll::Ready(None) => Ok(Poll::Ready(None)),
+            Poll::Pending => Ok(Poll::Pending),
+        }
+    }
+
+    fn from_result(result: StdResult<Poll<Option<T>>, E>) -> Self {
+        match result {
+            Ok(Poll::Ready(item)) => Poll::Ready(item.map(Ok)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(e) => Poll::Ready(Some(Err(e))),
+        }
+    }
+}
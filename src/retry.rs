@@ -0,0 +1,191 @@
+use crate::{ErrorClass, StdResult};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Describes how many times an operation is attempted and how long to wait
+/// between attempts.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use error_traits::Policy;
+///
+/// let fixed = Policy::fixed(3, Duration::from_millis(200));
+/// let exponential = Policy::exponential(5, Duration::from_millis(100))
+///     .with_max_delay(Duration::from_secs(2))
+///     .with_jitter();
+/// assert_eq!(fixed.delay(2), Duration::from_millis(200));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policy {
+    max_attempts: u32,
+    backoff: Backoff,
+    max_delay: Duration,
+    jitter: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backoff {
+    Fixed(Duration),
+    Exponential(Duration),
+}
+
+impl Policy {
+    /// At most `max_attempts` attempts (including the first one) with the same `delay` between them.
+    pub fn fixed(max_attempts: u32, delay: Duration) -> Self {
+        Self::new(max_attempts, Backoff::Fixed(delay))
+    }
+
+    /// At most `max_attempts` attempts (including the first one), delay starts
+    /// from `initial` and doubles after every failed attempt.
+    pub fn exponential(max_attempts: u32, initial: Duration) -> Self {
+        Self::new(max_attempts, Backoff::Exponential(initial))
+    }
+
+    fn new(max_attempts: u32, backoff: Backoff) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff,
+            max_delay: Duration::MAX,
+            jitter: false,
+        }
+    }
+
+    /// Caps delay between attempts.
+    pub fn with_max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+
+    /// Randomizes every delay to be anywhere between zero and its nominal value.
+    pub fn with_jitter(self) -> Self {
+        Self {
+            jitter: true,
+            ..self
+        }
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Delay to wait after `attempt`-th (starting from 1) failed attempt.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let delay = match self.backoff {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential(initial) => {
+                let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+                initial.saturating_mul(factor)
+            }
+        }
+        .min(self.max_delay);
+
+        if self.jitter {
+            delay.mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
+}
+
+/// Random number in `[0, 1)` without pulling in a `rand` dependency.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Calls a fallible closure until it succeeds, or attempts run out.
+/// Returns the first success, or the last error.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use error_traits::{Policy, RetryOnErr};
+///
+/// let mut attempts = 0;
+/// let fetch = || {
+///     attempts += 1;
+///     if attempts < 3 { Err("not yet") } else { Ok(attempts) }
+/// };
+/// let result = fetch.retry(Policy::fixed(3, Duration::from_millis(1)));
+/// assert_eq!(result, Ok(3));
+/// ```
+pub trait RetryOnErr<T, E> {
+    fn retry(self, policy: Policy) -> StdResult<T, E>;
+
+    /// Same as `retry`, but gives up on the first error that isn't transient
+    /// (see `ErrorClass::is_transient`).
+    fn retry_transient(self, policy: Policy) -> StdResult<T, E>
+    where
+        E: ErrorClass;
+
+    /// Same as `retry`, but logs every failed attempt using `LogErr`.
+    #[cfg(feature = "log_err")]
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn retry_logged(self, policy: Policy, log_prefix: &str) -> StdResult<T, E>
+    where
+        E: std::fmt::Display;
+}
+
+impl<F, T, E> RetryOnErr<T, E> for F
+where
+    F: FnMut() -> StdResult<T, E>,
+{
+    fn retry(self, policy: Policy) -> StdResult<T, E> {
+        retry_with(self, policy, |_| true, |_, _| ())
+    }
+
+    fn retry_transient(self, policy: Policy) -> StdResult<T, E>
+    where
+        E: ErrorClass,
+    {
+        retry_with(self, policy, E::is_transient, |_, _| ())
+    }
+
+    #[cfg(feature = "log_err")]
+    fn retry_logged(self, policy: Policy, log_prefix: &str) -> StdResult<T, E>
+    where
+        E: std::fmt::Display,
+    {
+        use crate::log_err::{log_err_from, TARGET};
+
+        let location = std::panic::Location::caller();
+        retry_with(
+            self,
+            policy,
+            |_| true,
+            |e, attempt| {
+                let max_attempts = policy.max_attempts();
+                let _ = log_err_from(Err::<(), _>(e), location, TARGET, log::Level::Error, || {
+                    format!("{log_prefix}attempt {attempt}/{max_attempts}: ")
+                });
+            },
+        )
+    }
+}
+
+fn retry_with<T, E>(
+    mut f: impl FnMut() -> StdResult<T, E>,
+    policy: Policy,
+    should_retry: impl Fn(&E) -> bool,
+    mut on_err: impl FnMut(&E, u32),
+) -> StdResult<T, E> {
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Err(e) if attempt < policy.max_attempts() && should_retry(&e) => {
+                on_err(&e, attempt);
+                std::thread::sleep(policy.delay(attempt));
+                attempt += 1;
+            }
+            Err(e) => {
+                on_err(&e, attempt);
+                return Err(e);
+            }
+            ok => return ok,
+        }
+    }
+}
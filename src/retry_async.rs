@@ -0,0 +1,187 @@
+use crate::{ErrorClass, Policy, StdResult};
+use std::future::Future;
+use std::time::Duration;
+
+/// Async counterpart of `RetryOnErr::retry`.
+/// Awaits future produced by `f` until it succeeds, or attempts run out,
+/// using `sleep` to wait between attempts, so it works with any runtime.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use error_traits::{retry_async_with, Policy};
+///
+/// let mut attempts = 0;
+/// let fetch = || {
+///     attempts += 1;
+///     let attempt = attempts;
+///     async move { if attempt < 3 { Err("not yet") } else { Ok(attempt) } }
+/// };
+/// let no_sleep = |_| async {};
+/// let policy = Policy::fixed(3, Duration::from_millis(1));
+/// let result = futures::executor::block_on(retry_async_with(policy, no_sleep, fetch));
+/// assert_eq!(result, Ok(3));
+/// ```
+pub async fn retry_async_with<F, Fut, T, E, S, SFut>(
+    policy: Policy,
+    sleep: S,
+    f: F,
+) -> StdResult<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = StdResult<T, E>>,
+    S: FnMut(Duration) -> SFut,
+    SFut: Future<Output = ()>,
+{
+    retry_async_impl(policy, sleep, f, |_| true, |_, _| ()).await
+}
+
+/// Same as `retry_async_with`, but gives up on the first error that isn't transient
+/// (see `ErrorClass::is_transient`), like `RetryOnErr::retry_transient`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use std::time::Duration;
+/// use error_traits::{retry_async_transient_with, Policy};
+///
+/// let mut attempts = 0;
+/// let fetch = || {
+///     attempts += 1;
+///     async { Err::<(), _>(io::Error::from(io::ErrorKind::NotFound)) }
+/// };
+/// let no_sleep = |_| async {};
+/// let policy = Policy::fixed(3, Duration::from_millis(1));
+/// let result = futures::executor::block_on(retry_async_transient_with(policy, no_sleep, fetch));
+/// assert!(result.is_err());
+/// assert_eq!(attempts, 1);
+/// ```
+pub async fn retry_async_transient_with<F, Fut, T, E, S, SFut>(
+    policy: Policy,
+    sleep: S,
+    f: F,
+) -> StdResult<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = StdResult<T, E>>,
+    E: ErrorClass,
+    S: FnMut(Duration) -> SFut,
+    SFut: Future<Output = ()>,
+{
+    retry_async_impl(policy, sleep, f, E::is_transient, |_, _| ()).await
+}
+
+/// Same as `retry_async_with`, but uses `tokio` timer.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use error_traits::{retry_async, Policy};
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+/// let policy = Policy::exponential(3, Duration::from_millis(1));
+/// let result = retry_async(policy, || async { "foo".parse::<u16>() }).await;
+/// assert!(result.is_err());
+/// # });
+/// ```
+#[cfg(feature = "retry_tokio")]
+pub async fn retry_async<F, Fut, T, E>(policy: Policy, f: F) -> StdResult<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = StdResult<T, E>>,
+{
+    retry_async_with(policy, tokio::time::sleep, f).await
+}
+
+/// Same as `retry_async_transient_with`, but uses `tokio` timer.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use std::time::Duration;
+/// use error_traits::{retry_async_transient, Policy};
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+/// let policy = Policy::fixed(3, Duration::from_millis(1));
+/// let result = retry_async_transient(policy, || async {
+///     Err::<(), _>(io::Error::from(io::ErrorKind::TimedOut))
+/// })
+/// .await;
+/// assert!(result.is_err());
+/// # });
+/// ```
+#[cfg(feature = "retry_tokio")]
+pub async fn retry_async_transient<F, Fut, T, E>(policy: Policy, f: F) -> StdResult<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = StdResult<T, E>>,
+    E: ErrorClass,
+{
+    retry_async_transient_with(policy, tokio::time::sleep, f).await
+}
+
+/// Same as `retry_async`, but logs every failed attempt using `LogErr`.
+/// With `log_location` feature, location is the one of this call.
+#[cfg(all(feature = "retry_tokio", feature = "log_err"))]
+#[cfg_attr(feature = "log_location", track_caller)]
+pub fn retry_async_logged<F, Fut, T, E>(
+    policy: Policy,
+    log_prefix: &str,
+    f: F,
+) -> impl Future<Output = StdResult<T, E>> + use<'_, F, Fut, T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = StdResult<T, E>>,
+    E: std::fmt::Display,
+{
+    use crate::log_err::{log_err_from, TARGET};
+
+    // Not an `async fn`, so that the location is captured before the first poll.
+    let location = std::panic::Location::caller();
+    retry_async_impl(
+        policy,
+        tokio::time::sleep,
+        f,
+        |_| true,
+        move |e, attempt| {
+            let max_attempts = policy.max_attempts();
+            let _ = log_err_from(Err::<(), _>(e), location, TARGET, log::Level::Error, || {
+                format!("{log_prefix}attempt {attempt}/{max_attempts}: ")
+            });
+        },
+    )
+}
+
+async fn retry_async_impl<F, Fut, T, E, S, SFut>(
+    policy: Policy,
+    mut sleep: S,
+    mut f: F,
+    should_retry: impl Fn(&E) -> bool,
+    mut on_err: impl FnMut(&E, u32),
+) -> StdResult<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = StdResult<T, E>>,
+    S: FnMut(Duration) -> SFut,
+    SFut: Future<Output = ()>,
+{
+    let mut attempt = 1;
+    loop {
+        match f().await {
+            Err(e) if attempt < policy.max_attempts() && should_retry(&e) => {
+                on_err(&e, attempt);
+                sleep(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                on_err(&e, attempt);
+                return Err(e);
+            }
+            ok => return ok,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::StdResult;
+use std::cell::RefCell;
+use std::error::Error;
+use std::rc::Rc;
+
+type Handler = Rc<dyn Fn(&dyn Error)>;
+
+thread_local! {
+    static HANDLERS: RefCell<Vec<Handler>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the handler even if the body panics.
+struct ScopeGuard;
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        HANDLERS.with(|handlers| handlers.borrow_mut().pop());
+    }
+}
+
+/// Runs `body` with `handler` installed for the current thread, so that every
+/// error passed through `HandledErr::handled` inside of it goes to `handler`.
+/// Scopes nest, the innermost one wins.
+/// Handlers are thread-local, so use `with_err_handler_async` for futures,
+/// as they may move between threads.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{with_err_handler, HandledErr};
+///
+/// fn parse_port() -> Result<u16, std::num::ParseIntError> {
+///     "foo".parse::<u16>().handled()
+/// }
+///
+/// let request_id = 42;
+/// let port = with_err_handler(
+///     move |e| eprintln!("[request {request_id}] {e}"),
+///     || parse_port(),
+/// );
+/// assert!(port.is_err());
+/// ```
+pub fn with_err_handler<R>(handler: impl Fn(&dyn Error) + 'static, body: impl FnOnce() -> R) -> R {
+    enter(Rc::new(handler), body)
+}
+
+fn enter<R>(handler: Handler, body: impl FnOnce() -> R) -> R {
+    HANDLERS.with(|handlers| handlers.borrow_mut().push(handler));
+    let _guard = ScopeGuard;
+    body()
+}
+
+/// Same as `with_err_handler`, but for a future: `handler` is installed
+/// during every poll of `fut`, on whatever thread it happens.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use error_traits::{with_err_handler_async, HandledErr};
+///
+/// async fn parse_port() -> Result<u16, std::num::ParseIntError> {
+///     "foo".parse::<u16>().handled()
+/// }
+///
+/// let handled = Arc::new(AtomicUsize::new(0));
+/// let counter = handled.clone();
+/// let port = futures::executor::block_on(with_err_handler_async(
+///     move |_| {
+///         counter.fetch_add(1, Ordering::Relaxed);
+///     },
+///     parse_port(),
+/// ));
+/// assert!(port.is_err());
+/// assert_eq!(handled.load(Ordering::Relaxed), 1);
+/// ```
+#[cfg(feature = "future_ext")]
+pub fn with_err_handler_async<Fut>(
+    handler: impl Fn(&dyn Error) + Send + Sync + 'static,
+    fut: Fut,
+) -> ErrHandlerScope<Fut>
+where
+    Fut: std::future::Future,
+{
+    ErrHandlerScope {
+        handler: std::sync::Arc::new(handler),
+        fut,
+    }
+}
+
+#[cfg(feature = "future_ext")]
+pin_project_lite::pin_project! {
+    /// Future for the `with_err_handler_async` function.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ErrHandlerScope<Fut> {
+        handler: std::sync::Arc<dyn Fn(&dyn Error) + Send + Sync>,
+        #[pin]
+        fut: Fut,
+    }
+}
+
+#[cfg(feature = "future_ext")]
+impl<Fut> std::future::Future for ErrHandlerScope<Fut>
+where
+    Fut: std::future::Future,
+{
+    type Output = Fut::Output;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let this = self.project();
+        let handler = this.handler.clone();
+        enter(Rc::new(move |e: &dyn Error| handler(e)), || {
+            this.fut.poll(cx)
+        })
+    }
+}
+
+/// If error is present, passes it to the innermost handler installed
+/// with `with_err_handler` (if any) and returns it back.
+pub trait HandledErr {
+    fn handled(self) -> Self;
+}
+
+impl<T, E> HandledErr for StdResult<T, E>
+where
+    E: Error + 'static,
+{
+    fn handled(self) -> Self {
+        if let Err(e) = &self {
+            // Cloned out, so that the handler is able to open scopes of its own.
+            let handler = HANDLERS.with(|handlers| handlers.borrow().last().cloned());
+            if let Some(handler) = handler {
+                handler(e)
+            }
+        }
+        self
+    }
+}
@@ -0,0 +1,48 @@
+use crate::StdResult;
+use std::error::Error;
+
+/// Tag under which `capture_err_tagged` attaches its prefix.
+const PREFIX_TAG: &str = "log_prefix";
+
+/// If error is present, sends it to Sentry with `sentry::capture_error`
+/// and returns it back. Does nothing unless a Sentry client is bound.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::CaptureErr;
+///
+/// let number = "foo".parse::<u16>().capture_err();
+/// let number = "foo".parse::<u16>().capture_err_tagged("config: ");
+/// ```
+pub trait CaptureErr
+where
+    Self: Sized,
+{
+    fn capture_err(self) -> Self;
+
+    /// Same as `capture_err`, but attaches `log_prefix` to the event as a `log_prefix` tag.
+    fn capture_err_tagged(self, log_prefix: &str) -> Self;
+}
+
+impl<T, E> CaptureErr for StdResult<T, E>
+where
+    E: Error,
+{
+    fn capture_err(self) -> Self {
+        if let Err(e) = &self {
+            sentry::capture_error(e);
+        }
+        self
+    }
+
+    fn capture_err_tagged(self, log_prefix: &str) -> Self {
+        if let Err(e) = &self {
+            sentry::with_scope(
+                |scope| scope.set_tag(PREFIX_TAG, log_prefix),
+                || sentry::capture_error(e),
+            );
+        }
+        self
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{ChainExt, ErrorCode};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+
+/// Structured representation of an error and its causes,
+/// meant to be returned as an error body (e.g. JSON) by services.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SerializableError {
+    pub message: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub type_name: String,
+    /// Messages of error's sources, from the closest one to the root cause.
+    pub chain: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// `file:line:column` the error was created at, see `Located::to_serializable_located`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Metadata attached to the error, see `WithMeta`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub meta: BTreeMap<String, String>,
+}
+
+impl SerializableError {
+    pub fn with_code(self, code: impl Into<String>) -> Self {
+        Self {
+            code: Some(code.into()),
+            ..self
+        }
+    }
+
+    pub fn with_location(self, location: impl Into<String>) -> Self {
+        Self {
+            location: Some(location.into()),
+            ..self
+        }
+    }
+
+    pub fn with_meta(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.meta.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Builds `SerializableError` from any error.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{AttachMeta, ToSerializableError, WithCode, WithContext};
+///
+/// let error = "foo".parse::<u16>().context("parsing port").unwrap_err();
+/// let body = serde_json::to_value(error.to_serializable().with_code("E_PORT")).unwrap();
+/// assert_eq!(body["message"], "parsing port");
+/// assert_eq!(body["chain"][0], "invalid digit found in string");
+/// assert_eq!(body["code"], "E_PORT");
+///
+/// let error = "foo".parse::<u16>().map_err_with_code("E_PORT").unwrap_err();
+/// assert_eq!(error.to_serializable_coded().code.as_deref(), Some("E_PORT"));
+///
+/// let error = "foo".parse::<u16>().attach_meta("shard", 7).unwrap_err();
+/// let body = serde_json::to_value(error.to_serializable_with_meta()).unwrap();
+/// assert_eq!(body["meta"]["shard"], "7");
+/// ```
+pub trait ToSerializableError {
+    fn to_serializable(&self) -> SerializableError;
+
+    /// Same as `to_serializable`, but fills `code` from error's `ErrorCode`.
+    fn to_serializable_coded(&self) -> SerializableError
+    where
+        Self: ErrorCode,
+    {
+        self.to_serializable().with_code(self.code())
+    }
+}
+
+impl<E> ToSerializableError for E
+where
+    E: Error + 'static,
+{
+    fn to_serializable(&self) -> SerializableError {
+        SerializableError {
+            message: self.to_string(),
+            type_name: std::any::type_name::<E>().to_owned(),
+            chain: self.chain().skip(1).map(|e| e.to_string()).collect(),
+            code: None,
+            location: None,
+            meta: BTreeMap::new(),
+        }
+    }
+}
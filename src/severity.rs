@@ -0,0 +1,45 @@
+/// How bad an error is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SeverityLevel {
+    Info,
+    Warning,
+    Error,
+    Fatal,
+}
+
+/// Lets error types declare their own severity once, instead of callers
+/// hardcoding it at every call site.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{Severity, SeverityLevel};
+///
+/// enum DbError {
+///     Timeout,
+///     Corrupted,
+/// }
+///
+/// impl Severity for DbError {
+///     fn severity(&self) -> SeverityLevel {
+///         match self {
+///             DbError::Timeout => SeverityLevel::Warning,
+///             DbError::Corrupted => SeverityLevel::Fatal,
+///         }
+///     }
+/// }
+///
+/// assert!(DbError::Corrupted.severity() > DbError::Timeout.severity());
+/// ```
+pub trait Severity {
+    fn severity(&self) -> SeverityLevel;
+}
+
+impl<E> Severity for &E
+where
+    E: Severity + ?Sized,
+{
+    fn severity(&self) -> SeverityLevel {
+        (**self).severity()
+    }
+}
@@ -0,0 +1,92 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use tracing_error::SpanTrace;
+
+/// Error together with the `SpanTrace` captured at the moment it was wrapped.
+/// Displays both the error and the spans it went through.
+///
+/// Spans are only recorded if `tracing_error::ErrorLayer` is installed.
+pub struct Traced<E> {
+    error: E,
+    span_trace: SpanTrace,
+}
+
+impl<E> Traced<E> {
+    /// Wraps `error`, capturing current span trace.
+    pub fn new(error: E) -> Self {
+        Self {
+            error,
+            span_trace: SpanTrace::capture(),
+        }
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn span_trace(&self) -> &SpanTrace {
+        &self.span_trace
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for Traced<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n\n{}", self.error, self.span_trace)
+    }
+}
+
+impl<E> Debug for Traced<E>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Traced")
+            .field("error", &self.error)
+            .field("span_trace", &self.span_trace)
+            .finish()
+    }
+}
+
+impl<E> Error for Traced<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Wraps error (if any) into `Traced`, capturing current span trace.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{Traced, WithSpanTrace};
+///
+/// let span = tracing::info_span!("parsing");
+/// let _guard = span.enter();
+/// let result: Result<u16, Traced<_>> = "foo".parse::<u16>().with_spantrace();
+/// ```
+pub trait WithSpanTrace {
+    type Ok;
+    type Err;
+
+    fn with_spantrace(self) -> StdResult<Self::Ok, Traced<Self::Err>>;
+}
+
+impl<T, E> WithSpanTrace for StdResult<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    fn with_spantrace(self) -> StdResult<T, Traced<E>> {
+        self.map_err(Traced::new)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{MapErrBy, PassErrWith, StdResult};
+use futures_core::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Same helpers as for `Result`, but applied to every item of a stream.
+/// Each method returns a wrapper stream.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::StreamResultExt;
+/// use futures::{stream, StreamExt};
+///
+/// let items = stream::iter(["1", "foo", "3"])
+///     .map(|s| s.parse::<u16>())
+///     .pass_err_with(|e| println!("[:: LOG ::] {e}"));
+/// let results: Vec<_> = futures::executor::block_on(items.collect());
+///
+/// let mut failures = 0;
+/// let items = stream::iter(["1", "foo", "bar"])
+///     .map(|s| s.parse::<u16>())
+///     .pass_err_with(|_| failures += 1);
+/// let _: Vec<_> = futures::executor::block_on(items.collect());
+/// assert_eq!(failures, 2);
+/// ```
+pub trait StreamResultExt
+where
+    Self: Stream<Item = StdResult<Self::Ok, Self::Error>> + Sized,
+{
+    type Ok;
+    type Error;
+
+    fn pass_err_with<F>(self, f: F) -> PassErrWithStream<Self, F>
+    where
+        F: FnMut(&Self::Error),
+    {
+        PassErrWithStream { stream: self, f }
+    }
+
+    fn map_err_by<N, F>(self, f: F) -> MapErrByStream<Self, F>
+    where
+        F: FnMut() -> N,
+    {
+        MapErrByStream { stream: self, f }
+    }
+
+    /// Logs every error yielded by the stream.
+    /// With `log_location` feature, location is the one of this call.
+    /// Requires an initialized logger.
+    #[cfg(feature = "log_err")]
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn log_err(self, log_prefix: &str) -> LogErrStream<'_, Self>
+    where
+        Self::Error: std::fmt::Display,
+    {
+        LogErrStream {
+            stream: self,
+            log_prefix,
+            location: std::panic::Location::caller(),
+        }
+    }
+}
+
+impl<S, T, E> StreamResultExt for S
+where
+    S: Stream<Item = StdResult<T, E>>,
+{
+    type Ok = T;
+    type Error = E;
+}
+
+pin_project_lite::pin_project! {
+    /// Stream for the `StreamResultExt::pass_err_with` method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct PassErrWithStream<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, T, E, F> Stream for PassErrWithStream<S, F>
+where
+    S: Stream<Item = StdResult<T, E>>,
+    F: FnMut(&E),
+{
+    type Item = StdResult<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream
+            .poll_next(cx)
+            .map(|item| item.map(|res| res.pass_err_with(&mut *this.f)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Stream for the `StreamResultExt::map_err_by` method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct MapErrByStream<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, T, E, N, F> Stream for MapErrByStream<S, F>
+where
+    S: Stream<Item = StdResult<T, E>>,
+    F: FnMut() -> N,
+{
+    type Item = StdResult<T, N>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream
+            .poll_next(cx)
+            .map(|item| item.map(|res| res.map_err_by(&mut *this.f)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(feature = "log_err")]
+pin_project_lite::pin_project! {
+    /// Stream for the `StreamResultExt::log_err` method.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct LogErrStream<'a, S> {
+        #[pin]
+        stream: S,
+        log_prefix: &'a str,
+        location: &'static std::panic::Location<'static>,
+    }
+}
+
+#[cfg(feature = "log_err")]
+impl<S, T, E> Stream for LogErrStream<'_, S>
+where
+    S: Stream<Item = StdResult<T, E>>,
+    E: std::fmt::Display,
+{
+    type Item = StdResult<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use crate::log_err::{log_err_from, TARGET};
+
+        let this = self.project();
+        this.stream.poll_next(cx).map(|item| {
+            item.map(|res| {
+                log_err_from(res, this.location, TARGET, log::Level::Error, || {
+                    this.log_prefix
+                })
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
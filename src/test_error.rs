@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Ready-made error type for examples and tests, enabled by `test_utils` feature.
+/// Displays its message, and optionally has another `TestError` as its source.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{ChainExt, TestError};
+///
+/// let error = TestError::new("loading config").with_source("file not found");
+/// assert_eq!(error.to_string(), "loading config");
+/// assert_eq!(error.root_cause().to_string(), "file not found");
+///
+/// let res: Result<(), TestError> = Err("boom".into());
+/// assert_eq!(res, Err(TestError::new("boom")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TestError {
+    message: String,
+    source: Option<Box<TestError>>,
+}
+
+impl TestError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Sets the source of the error, replacing the previous one.
+    pub fn with_source(self, source: impl Into<TestError>) -> Self {
+        Self {
+            source: Some(Box::new(source.into())),
+            ..self
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for TestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for TestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|e| e as _)
+    }
+}
+
+impl From<&str> for TestError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<String> for TestError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{ChainExt, StdResult};
+use std::error::Error;
+use std::fmt::Debug;
+
+/// Asserts that a result is an error, whose `Display` output
+/// contains the given substring. Panics with both messages otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::assert_err_display;
+///
+/// assert_err_display!("foo".parse::<u16>(), "invalid digit");
+/// ```
+///
+/// ```should_panic
+/// use error_traits::assert_err_display;
+///
+/// assert_err_display!("42".parse::<u16>(), "invalid digit");
+/// ```
+#[macro_export]
+macro_rules! assert_err_display {
+    ($result:expr, $expected:expr $(,)?) => {
+        match $result {
+            ::std::result::Result::Ok(ok) => {
+                ::std::panic!("expected an error, got `Ok({:?})`", ok)
+            }
+            ::std::result::Result::Err(error) => {
+                let (message, expected) = (::std::string::ToString::to_string(&error), $expected);
+                ::std::assert!(
+                    message.contains(expected),
+                    "error `{}` doesn't contain `{}`",
+                    message,
+                    expected,
+                );
+            }
+        }
+    };
+}
+
+/// Asserts that a result is an error matching the given pattern,
+/// with an optional `if` guard. Panics with the actual value otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::assert_err_matches;
+/// use std::num::IntErrorKind;
+///
+/// #[derive(Debug)]
+/// enum MyError {
+///     NotFound { id: u32 },
+///     Denied,
+/// }
+///
+/// assert_err_matches!(Err::<(), _>(MyError::NotFound { id: 7 }), MyError::NotFound { .. });
+/// assert_err_matches!(Err::<(), _>(MyError::NotFound { id: 7 }), MyError::NotFound { id } if id > 5);
+///
+/// let res = "".parse::<u8>().map_err(|e| e.kind().clone());
+/// assert_err_matches!(res, IntErrorKind::Empty);
+/// ```
+#[macro_export]
+macro_rules! assert_err_matches {
+    ($result:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        match $result {
+            ::std::result::Result::Err($pattern) $(if $guard)? => {}
+            ::std::result::Result::Err(error) => ::std::panic!(
+                "error `{:?}` doesn't match `{}`",
+                error,
+                ::std::stringify!($pattern $(if $guard)?),
+            ),
+            ::std::result::Result::Ok(ok) => {
+                ::std::panic!("expected an error, got `Ok({:?})`", ok)
+            }
+        }
+    };
+}
+
+/// Asserts that an error of type `C` is present in the chain
+/// (error itself and its sources) of the contained error and returns it.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{ExpectErrChain, WithContext};
+/// use std::num::ParseIntError;
+///
+/// let res = "foo".parse::<u16>().context("parsing port");
+/// let cause: &ParseIntError = res.expect_err_chain_contains::<ParseIntError>();
+/// assert_eq!(cause.to_string(), "invalid digit found in string");
+/// ```
+pub trait ExpectErrChain {
+    /// # Panics
+    ///
+    /// Panics if the result is `Ok`, or no error in the chain is `C`.
+    #[track_caller]
+    fn expect_err_chain_contains<C>(&self) -> &C
+    where
+        C: Error + 'static;
+}
+
+impl<T, E> ExpectErrChain for StdResult<T, E>
+where
+    T: Debug,
+    E: Error + 'static,
+{
+    #[track_caller]
+    fn expect_err_chain_contains<C>(&self) -> &C
+    where
+        C: Error + 'static,
+    {
+        match self {
+            Ok(ok) => panic!("expected an error, got `Ok({ok:?})`"),
+            Err(e) => e.find_source::<C>().unwrap_or_else(|| {
+                panic!(
+                    "no `{}` in error chain:\n{}",
+                    std::any::type_name::<C>(),
+                    e.display_chain()
+                )
+            }),
+        }
+    }
+}
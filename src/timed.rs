@@ -0,0 +1,107 @@
+use crate::StdResult;
+use std::time::{Duration, Instant};
+
+/// Runs `f`, then passes time it took, together with its result, to `on_done`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::time_with;
+///
+/// let number = time_with(
+///     || "foo".parse::<u16>(),
+///     |elapsed, res| println!("[:: LOG ::] parsing took {elapsed:?}, ok: {}", res.is_ok()),
+/// );
+/// ```
+pub fn time_with<T, E>(
+    f: impl FnOnce() -> StdResult<T, E>,
+    on_done: impl FnOnce(Duration, &StdResult<T, E>),
+) -> StdResult<T, E> {
+    let start = Instant::now();
+    let res = f();
+    on_done(start.elapsed(), &res);
+    res
+}
+
+/// Runs `f`, logging its error (if any) through `LogErr`, along with time it took.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::timed_err;
+///
+/// // Logs something like "fetching config: [after 1.2µs] invalid digit found in string".
+/// let number = timed_err("fetching config: ", || "foo".parse::<u16>());
+/// ```
+#[cfg(feature = "log_err")]
+#[cfg_attr(feature = "log_location", track_caller)]
+pub fn timed_err<T, E>(log_prefix: &str, f: impl FnOnce() -> StdResult<T, E>) -> StdResult<T, E>
+where
+    E: std::fmt::Display,
+{
+    let location = std::panic::Location::caller();
+    let start = Instant::now();
+    log_timed(f(), start, location, log_prefix)
+}
+
+/// Same as `time_with`, but for a future.
+#[cfg(feature = "future_ext")]
+pub async fn time_with_async<T, E>(
+    fut: impl std::future::Future<Output = StdResult<T, E>>,
+    on_done: impl FnOnce(Duration, &StdResult<T, E>),
+) -> StdResult<T, E> {
+    let start = Instant::now();
+    let res = fut.await;
+    on_done(start.elapsed(), &res);
+    res
+}
+
+/// Same as `timed_err`, but for a future.
+/// With `log_location` feature, location is the one of this call.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::timed_err_async;
+///
+/// let fut = timed_err_async("fetching config: ", async { "foo".parse::<u16>() });
+/// let number = futures::executor::block_on(fut);
+/// ```
+#[cfg(all(feature = "log_err", feature = "future_ext"))]
+#[cfg_attr(feature = "log_location", track_caller)]
+pub fn timed_err_async<Fut, T, E>(
+    log_prefix: &str,
+    fut: Fut,
+) -> impl std::future::Future<Output = StdResult<T, E>> + use<'_, Fut, T, E>
+where
+    Fut: std::future::Future<Output = StdResult<T, E>>,
+    E: std::fmt::Display,
+{
+    // Not an `async fn`, so that the location is captured before the first poll.
+    let location = std::panic::Location::caller();
+    async move {
+        let start = Instant::now();
+        let res = fut.await;
+        log_timed(res, start, location, log_prefix)
+    }
+}
+
+#[cfg(feature = "log_err")]
+fn log_timed<T, E>(
+    res: StdResult<T, E>,
+    start: Instant,
+    location: &'static std::panic::Location<'static>,
+    log_prefix: &str,
+) -> StdResult<T, E>
+where
+    E: std::fmt::Display,
+{
+    use crate::log_err::{log_err_from, TARGET};
+
+    let elapsed = start.elapsed();
+    log_err_from(res, location, TARGET, log::Level::Error, || {
+        format!("{log_prefix}[after {elapsed:?}] ")
+    })
+}
@@ -0,0 +1,157 @@
+use crate::{PanicError, StdResult};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use tokio::task::JoinError;
+
+/// Failure of a spawned task: its own error, a panic, or a cancellation.
+#[derive(Debug)]
+pub enum TaskError<E> {
+    Failed(E),
+    Panicked(PanicError),
+    Cancelled,
+}
+
+impl<E> Display for TaskError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskError::Failed(e) => Display::fmt(e, f),
+            TaskError::Panicked(e) => write!(f, "task {e}"),
+            TaskError::Cancelled => f.write_str("task was cancelled"),
+        }
+    }
+}
+
+impl<E> Error for TaskError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TaskError::Failed(e) => e.source(),
+            TaskError::Panicked(e) => Some(e),
+            TaskError::Cancelled => None,
+        }
+    }
+}
+
+impl<E> From<JoinError> for TaskError<E> {
+    fn from(e: JoinError) -> Self {
+        match e.try_into_panic() {
+            Ok(payload) => TaskError::Panicked(PanicError::from_payload(payload)),
+            Err(_) => TaskError::Cancelled,
+        }
+    }
+}
+
+/// Collapses `Result<Result<T, E>, JoinError>`, returned by awaiting a `JoinHandle`,
+/// into one `Result`, telling panics from cancellations.
+/// With `log_err` feature, panics are logged through `LogErr`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{FlattenJoin, TaskError};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let result = tokio::spawn(async { "foo".parse::<u16>() }).await.flatten_join();
+/// assert!(matches!(result, Err(TaskError::Failed(_))));
+///
+/// let result = tokio::spawn(async { Ok::<u16, ()>(panic!("boom")) }).await.flatten_join();
+/// assert!(matches!(result, Err(TaskError::Panicked(e)) if e.message() == "boom"));
+/// # }
+/// ```
+pub trait FlattenJoin<T, E> {
+    #[cfg_attr(feature = "log_location", track_caller)]
+    fn flatten_join(self) -> StdResult<T, TaskError<E>>;
+}
+
+impl<T, E> FlattenJoin<T, E> for StdResult<StdResult<T, E>, JoinError> {
+    fn flatten_join(self) -> StdResult<T, TaskError<E>> {
+        match self {
+            Ok(res) => res.map_err(TaskError::Failed),
+            Err(e) => {
+                let e = TaskError::from(e);
+                #[cfg(feature = "log_err")]
+                if let TaskError::Panicked(panic) = &e {
+                    use crate::LogErr;
+
+                    let _ = Err::<(), _>(panic).log_err("task ");
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Spawns a fire-and-forget task, logging its error or panic through `LogErr`
+/// with `log_prefix`, whether the returned handle is awaited or not.
+/// Requires an initialized logger and a `tokio` runtime.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{spawn_logged, TaskError};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let handle = spawn_logged("cache refresh failed: ", async { "foo".parse::<u16>() });
+/// assert!(matches!(handle.await, Err(TaskError::Failed(_))));
+///
+/// spawn_logged("cleanup failed: ", async { Ok::<_, String>(()) });
+/// # }
+/// ```
+#[cfg(feature = "log_err")]
+#[cfg_attr(feature = "log_location", track_caller)]
+pub fn spawn_logged<F, T, E>(log_prefix: impl Into<String>, fut: F) -> LoggedJoinHandle<T, E>
+where
+    F: std::future::Future<Output = StdResult<T, E>> + Send + 'static,
+    T: Send + 'static,
+    E: Display + Send + 'static,
+{
+    use crate::log_err::{log_err_from, TARGET};
+
+    let log_prefix = log_prefix.into();
+    let location = std::panic::Location::caller();
+    let handle = tokio::spawn(async move {
+        let res = match crate::catch_panic_async(std::panic::AssertUnwindSafe(fut)).await {
+            Ok(res) => res.map_err(TaskError::Failed),
+            Err(panic) => Err(TaskError::Panicked(panic)),
+        };
+        log_err_from(res, location, TARGET, log::Level::Error, || &log_prefix)
+    });
+    LoggedJoinHandle { handle }
+}
+
+/// Handle of a task spawned with `spawn_logged`.
+/// Resolves to the task's output, with `JoinError` already folded into `TaskError`.
+#[cfg(feature = "log_err")]
+#[derive(Debug)]
+pub struct LoggedJoinHandle<T, E> {
+    handle: tokio::task::JoinHandle<StdResult<T, TaskError<E>>>,
+}
+
+#[cfg(feature = "log_err")]
+impl<T, E> LoggedJoinHandle<T, E> {
+    /// Cancels the task, see `JoinHandle::abort`.
+    pub fn abort(&self) {
+        self.handle.abort()
+    }
+}
+
+#[cfg(feature = "log_err")]
+impl<T, E> std::future::Future for LoggedJoinHandle<T, E> {
+    type Output = StdResult<T, TaskError<E>>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        std::pin::Pin::new(&mut self.handle)
+            .poll(cx)
+            .map(|res| res.unwrap_or_else(|e| Err(e.into())))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{ChainExt, ErrorClass, ErrorKindClass, StdResult};
+use std::error::Error;
+use std::io;
+use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
+
+/// gRPC status code to respond with when an error reaches a service method.
+/// Counterpart of `HttpStatus` for `tonic`.
+pub trait GrpcCode {
+    fn grpc_code(&self) -> Code;
+}
+
+impl GrpcCode for ErrorKindClass {
+    fn grpc_code(&self) -> Code {
+        match self {
+            ErrorKindClass::Transient => Code::Unavailable,
+            ErrorKindClass::RateLimited => Code::ResourceExhausted,
+            ErrorKindClass::Permanent => Code::Internal,
+            ErrorKindClass::Unknown => Code::Unknown,
+        }
+    }
+}
+
+impl GrpcCode for io::ErrorKind {
+    fn grpc_code(&self) -> Code {
+        use io::ErrorKind::*;
+
+        match self {
+            NotFound => Code::NotFound,
+            PermissionDenied => Code::PermissionDenied,
+            AlreadyExists => Code::AlreadyExists,
+            InvalidInput | InvalidData => Code::InvalidArgument,
+            TimedOut => Code::DeadlineExceeded,
+            Unsupported => Code::Unimplemented,
+            kind => kind.class().grpc_code(),
+        }
+    }
+}
+
+impl GrpcCode for io::Error {
+    fn grpc_code(&self) -> Code {
+        self.kind().grpc_code()
+    }
+}
+
+impl<E> GrpcCode for crate::Coded<E>
+where
+    E: GrpcCode,
+{
+    fn grpc_code(&self) -> Code {
+        self.error().grpc_code()
+    }
+}
+
+impl<E> GrpcCode for crate::Contextualized<E>
+where
+    E: GrpcCode,
+{
+    fn grpc_code(&self) -> Code {
+        self.error().grpc_code()
+    }
+}
+
+impl<E> GrpcCode for &E
+where
+    E: GrpcCode + ?Sized,
+{
+    fn grpc_code(&self) -> Code {
+        (**self).grpc_code()
+    }
+}
+
+/// Turns error into `tonic::Status` with its `GrpcCode`. Message is the whole chain
+/// (see `ChainExt::display_chain`), and details hold a standard `google.rpc.DebugInfo`
+/// with the error's message as `detail` and messages of its sources as `stack_entries`.
+/// Named differently from `MapErrToStatus::map_err_to_status`, so both can be imported at once.
+///
+/// # Examples
+///
+/// ```
+/// use tonic::Code;
+/// use tonic_types::StatusExt;
+/// use error_traits::{MapErrToGrpcStatus, WithContext};
+///
+/// let status = std::fs::read("missing/config.toml")
+///     .context("reading config")
+///     .map_err_to_grpc_status()
+///     .unwrap_err();
+/// assert_eq!(status.code(), Code::NotFound);
+/// assert!(status.message().starts_with("error: reading config\ncaused by: "));
+///
+/// let debug_info = status.get_details_debug_info().unwrap();
+/// assert_eq!(debug_info.detail, "reading config");
+/// assert_eq!(debug_info.stack_entries.len(), 1);
+/// ```
+pub trait MapErrToGrpcStatus<T> {
+    fn map_err_to_grpc_status(self) -> StdResult<T, Status>;
+}
+
+impl<T, E> MapErrToGrpcStatus<T> for StdResult<T, E>
+where
+    E: Error + GrpcCode + 'static,
+{
+    fn map_err_to_grpc_status(self) -> StdResult<T, Status> {
+        self.map_err(|e| {
+            let sources: Vec<String> = e.chain().skip(1).map(|e| e.to_string()).collect();
+            Status::with_error_details(
+                e.grpc_code(),
+                e.display_chain().to_string(),
+                ErrorDetails::with_debug_info(sources, e.to_string()),
+            )
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use crate::StdResult;
+use std::fmt::Display;
+use tracing::Level;
+
+/// If error is present, this trait emits a `tracing` event with the error
+/// recorded as the `error` field and returns it back.
+/// Mirrors `LogErr`, but doesn't require the `log` bridge.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::SocketAddr;
+/// use error_traits::TraceErr;
+///
+/// let error = "foo".parse::<SocketAddr>().trace_err("failed to parse address");
+/// let warning = "foo".parse::<SocketAddr>().trace_warn_err("failed to parse address");
+/// ```
+pub trait TraceErr
+where
+    Self: Sized,
+{
+    fn trace_err_at(self, level: Level, message: &str) -> Self;
+
+    fn trace_err(self, message: &str) -> Self {
+        self.trace_err_at(Level::ERROR, message)
+    }
+
+    fn trace_warn_err(self, message: &str) -> Self {
+        self.trace_err_at(Level::WARN, message)
+    }
+
+    fn trace_info_err(self, message: &str) -> Self {
+        self.trace_err_at(Level::INFO, message)
+    }
+
+    fn trace_debug_err(self, message: &str) -> Self {
+        self.trace_err_at(Level::DEBUG, message)
+    }
+
+    fn trace_trace_err(self, message: &str) -> Self {
+        self.trace_err_at(Level::TRACE, message)
+    }
+}
+
+impl<T, E> TraceErr for StdResult<T, E>
+where
+    E: Display,
+{
+    fn trace_err_at(self, level: Level, message: &str) -> Self {
+        if let Err(e) = &self {
+            // `event!` needs a constant level, hence the match.
+            match level {
+                Level::ERROR => tracing::event!(Level::ERROR, error = %e, "{message}"),
+                Level::WARN => tracing::event!(Level::WARN, error = %e, "{message}"),
+                Level::INFO => tracing::event!(Level::INFO, error = %e, "{message}"),
+                Level::DEBUG => tracing::event!(Level::DEBUG, error = %e, "{message}"),
+                Level::TRACE => tracing::event!(Level::TRACE, error = %e, "{message}"),
+            }
+        }
+        self
+    }
+}
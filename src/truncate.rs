@@ -0,0 +1,55 @@
+use crate::StdResult;
+use std::borrow::Cow;
+
+/// Cuts `msg` down to at most `max_len` bytes (on a char boundary), appending
+/// an ellipsis and the original length. Short messages are returned as is.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::truncate_msg;
+///
+/// assert_eq!(truncate_msg("short", 10), "short");
+/// assert_eq!(truncate_msg("request body: 0123456789", 13), "request body:… (24 bytes)");
+/// ```
+pub fn truncate_msg(msg: &str, max_len: usize) -> Cow<'_, str> {
+    if msg.len() <= max_len {
+        return Cow::Borrowed(msg);
+    }
+    let mut end = max_len;
+    while !msg.is_char_boundary(end) {
+        end -= 1;
+    }
+    Cow::Owned(format!("{}… ({} bytes)", &msg[..end], msg.len()))
+}
+
+/// Same as `MapErrToString`, but caps the message with `truncate_msg`.
+/// Some errors embed whole request bodies, which is too much for a log line.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::MapErrToTruncatedString;
+///
+/// let result: Result<(), String> = Err("x".repeat(10_000));
+/// let error = result.map_err_to_str_truncated(16).unwrap_err();
+/// assert_eq!(error, "xxxxxxxxxxxxxxxx… (10000 bytes)");
+/// ```
+pub trait MapErrToTruncatedString<T> {
+    fn map_err_to_str_truncated(self, max_len: usize) -> StdResult<T, String>;
+}
+
+impl<T, E> MapErrToTruncatedString<T> for StdResult<T, E>
+where
+    E: ToString,
+{
+    fn map_err_to_str_truncated(self, max_len: usize) -> StdResult<T, String> {
+        self.map_err(|e| {
+            let msg = e.to_string();
+            match truncate_msg(&msg, max_len) {
+                Cow::Borrowed(_) => msg,
+                Cow::Owned(truncated) => truncated,
+            }
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{MultiError, StdResult};
+
+/// Merges a tuple of independent `Result`s with the same error type,
+/// returning either all successes, or every error (not just the first one).
+/// Implemented for tuples of up to 12 elements.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::TupleResults;
+///
+/// let (port, retries) = ("8080".parse::<u16>(), "3".parse::<u16>()).merge_results().unwrap();
+/// assert_eq!((port, retries), (8080, 3));
+///
+/// let merged = ("foo".parse::<u16>(), "1".parse::<u16>(), "bar".parse::<u16>()).merge_results();
+/// assert_eq!(merged.unwrap_err().len(), 2);
+/// ```
+pub trait TupleResults {
+    type Ok;
+    type Err;
+
+    fn merge_results(self) -> StdResult<Self::Ok, MultiError<Self::Err>>;
+}
+
+/// Function form of `TupleResults::merge_results`.
+pub fn merge_results<R>(results: R) -> StdResult<R::Ok, MultiError<R::Err>>
+where
+    R: TupleResults,
+{
+    results.merge_results()
+}
+
+macro_rules! impl_tuple_results {
+    ($($T:ident $r:ident),+) => {
+        impl<E, $($T),+> TupleResults for ($(StdResult<$T, E>,)+) {
+            type Ok = ($($T,)+);
+            type Err = E;
+
+            fn merge_results(self) -> StdResult<Self::Ok, MultiError<E>> {
+                let ($($r,)+) = self;
+                let mut errors = MultiError::new();
+                $(let $r = $r.map_err(|e| errors.push(e)).ok();)+
+                match ($($r,)+) {
+                    ($(Some($r),)+) => Ok(($($r,)+)),
+                    _ => Err(errors),
+                }
+            }
+        }
+    };
+}
+
+impl_tuple_results!(A a);
+impl_tuple_results!(A a, B b);
+impl_tuple_results!(A a, B b, C c);
+impl_tuple_results!(A a, B b, C c, D d);
+impl_tuple_results!(A a, B b, C c, D d, F f);
+impl_tuple_results!(A a, B b, C c, D d, F f, G g);
+impl_tuple_results!(A a, B b, C c, D d, F f, G g, H h);
+impl_tuple_results!(A a, B b, C c, D d, F f, G g, H h, I i);
+impl_tuple_results!(A a, B b, C c, D d, F f, G g, H h, I i, J j);
+impl_tuple_results!(A a, B b, C c, D d, F f, G g, H h, I i, J j, K k);
+impl_tuple_results!(A a, B b, C c, D d, F f, G g, H h, I i, J j, K k, L l);
+impl_tuple_results!(A a, B b, C c, D d, F f, G g, H h, I i, J j, K k, L l, M m);
@@ -0,0 +1,79 @@
+use crate::{MultiError, StdResult};
+
+/// Accumulating dual of `Result`: combining two `Invalid` values keeps
+/// errors of both, instead of short-circuiting on the first one.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::Validated;
+///
+/// struct Config {
+///     port: u16,
+///     retries: u8,
+/// }
+///
+/// let port = Validated::from("foo".parse::<u16>().map_err(|e| e.to_string()));
+/// let retries = Validated::from("bar".parse::<u8>().map_err(|e| e.to_string()));
+/// let config = port
+///     .zip(retries)
+///     .map(|(port, retries)| Config { port, retries })
+///     .into_result();
+/// assert_eq!(config.err().unwrap().len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validated<T, E> {
+    Valid(T),
+    Invalid(MultiError<E>),
+}
+
+impl<T, E> Validated<T, E> {
+    pub fn invalid(error: E) -> Self {
+        Self::Invalid(MultiError::from(vec![error]))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid(_))
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Validated<U, E> {
+        match self {
+            Self::Valid(t) => Validated::Valid(f(t)),
+            Self::Invalid(errors) => Validated::Invalid(errors),
+        }
+    }
+
+    /// Returns `other` if both are valid, accumulating errors of both otherwise.
+    pub fn and<U>(self, other: Validated<U, E>) -> Validated<U, E> {
+        self.zip(other).map(|(_, u)| u)
+    }
+
+    /// Pairs values if both are valid, accumulating errors of both otherwise.
+    pub fn zip<U>(self, other: Validated<U, E>) -> Validated<(T, U), E> {
+        match (self, other) {
+            (Self::Valid(t), Validated::Valid(u)) => Validated::Valid((t, u)),
+            (Self::Valid(_), Validated::Invalid(errors))
+            | (Self::Invalid(errors), Validated::Valid(_)) => Validated::Invalid(errors),
+            (Self::Invalid(mut errors), Validated::Invalid(other_errors)) => {
+                errors.extend(other_errors);
+                Validated::Invalid(errors)
+            }
+        }
+    }
+
+    pub fn into_result(self) -> StdResult<T, MultiError<E>> {
+        match self {
+            Self::Valid(t) => Ok(t),
+            Self::Invalid(errors) => Err(errors),
+        }
+    }
+}
+
+impl<T, E> From<StdResult<T, E>> for Validated<T, E> {
+    fn from(res: StdResult<T, E>) -> Self {
+        match res {
+            Ok(t) => Self::Valid(t),
+            Err(e) => Self::invalid(e),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::StdResult;
+use std::fmt::Display;
+
+/// If error is present, this trait writes it to the browser console with
+/// `console.error` and returns back.
+/// Doesn't need a `log`-to-console bridge, only available on `wasm32`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use error_traits::ConsoleErr;
+///
+/// let number = "foo".parse::<u16>().console_err("parsing input: ");
+/// ```
+pub trait ConsoleErr {
+    fn console_err(self, log_prefix: &str) -> Self;
+}
+
+impl<T, E> ConsoleErr for StdResult<T, E>
+where
+    E: Display,
+{
+    fn console_err(self, log_prefix: &str) -> Self {
+        if let Err(e) = &self {
+            web_sys::console::error_1(&format!("{log_prefix}{e}").into())
+        }
+        self
+    }
+}
@@ -0,0 +1,193 @@
+use crate::StdResult;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Error with arbitrary key-value metadata attached, like `shard=7`.
+/// Displays as `error [key=value, ...]`, while `source()` is the one
+/// of the wrapped error.
+///
+/// Use `AndMeta::and_meta` to add more pairs to the same wrapper
+/// instead of nesting it.
+#[derive(Debug, Clone)]
+pub struct WithMeta<E> {
+    meta: Vec<(&'static str, String)>,
+    error: E,
+}
+
+impl<E> WithMeta<E> {
+    pub fn new(error: E) -> Self {
+        Self {
+            meta: Vec::new(),
+            error,
+        }
+    }
+
+    /// Adds a pair to this wrapper.
+    pub fn attach_meta(mut self, key: &'static str, value: impl Display) -> Self {
+        self.meta.push((key, value.to_string()));
+        self
+    }
+
+    /// Pairs attached to this wrapper, in order of attaching.
+    pub fn meta(&self) -> &[(&'static str, String)] {
+        &self.meta
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for WithMeta<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)?;
+        for (i, (key, value)) in self.meta.iter().enumerate() {
+            let separator = if i == 0 { " [" } else { ", " };
+            write!(f, "{separator}{key}={value}")?;
+        }
+        if !self.meta.is_empty() {
+            f.write_str("]")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> Error for WithMeta<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<E> WithMeta<E>
+where
+    E: Error + 'static,
+{
+    /// Same as `ToSerializableError::to_serializable`, but also fills `meta`
+    /// with pairs of this wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use error_traits::{AndMeta, AttachMeta};
+    ///
+    /// let error = "foo"
+    ///     .parse::<u16>()
+    ///     .attach_meta("shard", 7)
+    ///     .and_meta("table", "users")
+    ///     .unwrap_err();
+    /// let json = serde_json::to_value(error.to_serializable_with_meta()).unwrap();
+    /// assert_eq!(json["meta"], serde_json::json!({ "shard": "7", "table": "users" }));
+    /// assert_eq!(json["message"], "invalid digit found in string [shard=7, table=users]");
+    /// ```
+    pub fn to_serializable_with_meta(&self) -> crate::SerializableError {
+        let report = crate::ToSerializableError::to_serializable(self);
+        self.meta
+            .iter()
+            .fold(report, |report, (key, value)| report.with_meta(*key, value))
+    }
+}
+
+/// Attaches the given pair to error (if any), wrapping it into `WithMeta`.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::AttachMeta;
+///
+/// let res = "foo".parse::<u16>().attach_meta("shard", 7);
+/// let error = res.unwrap_err();
+/// assert_eq!(error.to_string(), "invalid digit found in string [shard=7]");
+/// assert_eq!(error.meta(), [("shard", "7".to_owned())]);
+/// ```
+pub trait AttachMeta<T, E> {
+    fn attach_meta(self, key: &'static str, value: impl Display) -> StdResult<T, WithMeta<E>>;
+}
+
+impl<T, E> AttachMeta<T, E> for StdResult<T, E> {
+    fn attach_meta(self, key: &'static str, value: impl Display) -> StdResult<T, WithMeta<E>> {
+        self.map_err(|error| WithMeta::new(error).attach_meta(key, value))
+    }
+}
+
+/// Adds the given pair to `WithMeta` error (if any), without nesting
+/// another wrapper.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{AndMeta, AttachMeta};
+///
+/// let table = "users";
+/// let res = "foo".parse::<u16>().attach_meta("shard", 7).and_meta("table", table);
+/// let error = res.unwrap_err();
+/// assert_eq!(error.to_string(), "invalid digit found in string [shard=7, table=users]");
+/// assert_eq!(error.meta(), [("shard", "7".to_owned()), ("table", "users".to_owned())]);
+/// ```
+pub trait AndMeta
+where
+    Self: Sized,
+{
+    fn and_meta(self, key: &'static str, value: impl Display) -> Self;
+}
+
+impl<T, E> AndMeta for StdResult<T, WithMeta<E>> {
+    fn and_meta(self, key: &'static str, value: impl Display) -> Self {
+        self.map_err(|error| error.attach_meta(key, value))
+    }
+}
+
+/// If error is present, logs it with `LogErrKv`, with pairs of `WithMeta`
+/// as structured fields.
+/// Requires an initialized logger.
+///
+/// # Examples
+///
+/// ```
+/// use error_traits::{AttachMeta, LogErrMeta};
+///
+/// let res = "foo".parse::<u16>().attach_meta("shard", 7).log_err_meta("failed to parse port");
+/// ```
+#[cfg(feature = "log_err_kv")]
+pub trait LogErrMeta
+where
+    Self: Sized,
+{
+    fn log_err_meta_at(self, level: log::Level, log_msg: &str) -> Self;
+
+    fn log_err_meta(self, log_msg: &str) -> Self {
+        self.log_err_meta_at(log::Level::Error, log_msg)
+    }
+}
+
+#[cfg(feature = "log_err_kv")]
+impl<T, E> LogErrMeta for StdResult<T, WithMeta<E>>
+where
+    E: Error,
+{
+    fn log_err_meta_at(self, level: log::Level, log_msg: &str) -> Self {
+        use crate::LogErrKv;
+
+        if let Err(e) = &self {
+            let values: Vec<&str> = e.meta.iter().map(|(_, v)| v.as_str()).collect();
+            let kvs: Vec<(&str, &dyn log::kv::ToValue)> = e
+                .meta
+                .iter()
+                .zip(&values)
+                .map(|((k, _), v)| (*k, v as _))
+                .collect();
+            let _ = Err::<(), _>(e).log_err_kv_at(level, log_msg, &kvs);
+        }
+        self
+    }
+}